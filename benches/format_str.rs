@@ -55,6 +55,16 @@ fn encode_simple(b: &mut Bencher) {
     })
 }
 
+#[bench]
+fn encode_simple_array(b: &mut Bencher) {
+    let uuid = Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4").unwrap();
+    b.iter(|| {
+        let mut buffer = [0_u8; 32];
+        uuid.encode_simple_lower(&mut buffer);
+        buffer
+    })
+}
+
 #[bench]
 fn encode_urn(b: &mut Bencher) {
     let uuid = Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4").unwrap();
@@ -3,9 +3,36 @@
 extern crate test;
 
 use test::Bencher;
-use uuid::Uuid;
+use uuid::{Builder, Uuid, UuidPool};
 
 #[bench]
 fn new_v4(b: &mut Bencher) {
-    b.iter(|| Uuid::new_v4());
+    b.iter(Uuid::new_v4);
+}
+
+#[bench]
+fn new_v4_pool(b: &mut Bencher) {
+    let mut pool = UuidPool::new();
+
+    b.iter(|| pool.next());
+}
+
+// Reads from the operating system's RNG on every call, which is what
+// `new_v4` does without the `fast-rng` feature
+#[bench]
+fn new_v4_getrandom(b: &mut Bencher) {
+    b.iter(|| {
+        let mut bytes = [0; 16];
+        getrandom::getrandom(&mut bytes).unwrap();
+
+        Builder::from_random_bytes(bytes).into_uuid()
+    });
+}
+
+#[cfg(feature = "fast-rng")]
+#[bench]
+fn new_v4_fast_generator(b: &mut Bencher) {
+    let mut generator = uuid::FastV4Generator::new();
+
+    b.iter(|| generator.next());
 }
@@ -0,0 +1,6 @@
+fn main() {
+    // Declare the `uuid_unstable` cfg so newer compilers don't warn about it.
+    // Older versions of Cargo don't know this instruction and ignore it.
+    println!("cargo:rustc-check-cfg=cfg(uuid_unstable)");
+    println!("cargo:rerun-if-changed=build.rs");
+}
@@ -0,0 +1,17 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use std::str;
+use uuid::Uuid;
+
+fuzz_target!(|data: &[u8]| {
+    // Ensure the byte parser doesn't panic on arbitrary input
+    let _ = Uuid::try_parse_ascii(data);
+
+    if let Ok(uuid) = str::from_utf8(data) {
+        // Ensure building a detailed error doesn't panic either
+        if let Err(err) = Uuid::parse_str(uuid) {
+            let _ = err.to_string();
+        }
+    }
+});
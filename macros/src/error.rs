@@ -1,3 +1,4 @@
+use crate::parser::strip_urn_prefix;
 use std::fmt;
 
 /// A general error that can occur when working with UUIDs.
@@ -43,10 +44,10 @@ impl<'a> InvalidUuid<'a> {
     pub fn into_err(self) -> Error {
         let (s, offset, simple) = match self.0.as_bytes() {
             [b'{', s @ .., b'}'] => (s, 1, false),
-            [b'u', b'r', b'n', b':', b'u', b'u', b'i', b'd', b':', s @ ..] => {
-                (s, "urn:uuid:".len(), false)
-            }
-            s => (s, 0, true),
+            s => match strip_urn_prefix(s) {
+                Some(s) => (s, "urn:uuid:".len(), false),
+                None => (s, 0, true),
+            },
         };
 
         let mut hyphen_count = 0;
@@ -70,7 +71,7 @@ impl<'a> InvalidUuid<'a> {
                     group_bounds[hyphen_count] = index;
                 }
                 hyphen_count += 1;
-            } else if !matches!(byte, b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F') {
+            } else if !byte.is_ascii_hexdigit() {
                 // Non-hex char
                 return Error(ErrorKind::Char {
                     character: byte as char,
@@ -11,20 +11,38 @@
 
 use crate::error::InvalidUuid;
 
+/// Strip a `urn:uuid:` prefix, in any case, from the start of the input.
+pub(crate) const fn strip_urn_prefix(s: &[u8]) -> Option<&[u8]> {
+    match s {
+        [u1, r, n, b':', u2, u3, i, d, b':', s @ ..]
+            if u1.eq_ignore_ascii_case(&b'u')
+                && r.eq_ignore_ascii_case(&b'r')
+                && n.eq_ignore_ascii_case(&b'n')
+                && u2.eq_ignore_ascii_case(&b'u')
+                && u3.eq_ignore_ascii_case(&b'u')
+                && i.eq_ignore_ascii_case(&b'i')
+                && d.eq_ignore_ascii_case(&b'd') =>
+        {
+            Some(s)
+        }
+        _ => None,
+    }
+}
+
 #[inline]
-pub const fn try_parse(input: &str) -> Result<[u8; 16], InvalidUuid> {
+pub const fn try_parse(input: &str) -> Result<[u8; 16], InvalidUuid<'_>> {
     let result = match (input.len(), input.as_bytes()) {
         // Inputs of 32 bytes must be a non-hyphenated UUID
         (32, s) => parse_simple(s),
         // Hyphenated UUIDs may be wrapped in various ways:
         // - `{UUID}` for braced UUIDs
-        // - `urn:uuid:UUID` for URNs
+        // - `urn:uuid:UUID` for URNs, with the prefix in any case
         // - `UUID` for a regular hyphenated UUID
-        (36, s)
-        | (38, [b'{', s @ .., b'}'])
-        | (45, [b'u', b'r', b'n', b':', b'u', b'u', b'i', b'd', b':', s @ ..]) => {
-            parse_hyphenated(s)
-        }
+        (36, s) | (38, [b'{', s @ .., b'}']) => parse_hyphenated(s),
+        (45, s) => match strip_urn_prefix(s) {
+            Some(s) => parse_hyphenated(s),
+            None => Err(()),
+        },
         // Any other shaped input is immediately invalid
         _ => Err(()),
     };
@@ -13,7 +13,7 @@
 //!
 //! [`Uuid`]: ../struct.Uuid.html
 
-use crate::{error::*, timestamp, Bytes, Uuid, Variant, Version};
+use crate::{error::*, timestamp, Bytes, Endianness, Uuid, Variant, Version};
 
 /// A builder for creating a UUID.
 ///
@@ -50,7 +50,8 @@ impl Uuid {
     /// The 'nil UUID' (all zeros).
     ///
     /// The nil UUID is a special form of UUID that is specified to have all
-    /// 128 bits set to zero.
+    /// 128 bits set to zero. It's also the value returned by `Uuid::default()`,
+    /// so structs containing a `Uuid` can `#[derive(Default)]`.
     ///
     /// # References
     ///
@@ -120,25 +121,20 @@ impl Uuid {
     ///     uuid.hyphenated().to_string(),
     /// );
     /// ```
+    ///
+    /// The length of `d4` is checked at compile time. For a slice whose
+    /// length is only known at runtime, use [`Uuid::from_fields_slice`]:
+    ///
+    /// ```compile_fail
+    /// # use uuid::Uuid;
+    /// let d4 = [0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7];
+    ///
+    /// let uuid = Uuid::from_fields(0xa1a2a3a4, 0xb1b2, 0xc1c2, &d4);
+    /// ```
+    ///
+    /// [`Uuid::from_fields_slice`]: #method.from_fields_slice
     pub const fn from_fields(d1: u32, d2: u16, d3: u16, d4: &[u8; 8]) -> Uuid {
-        Uuid::from_bytes([
-            (d1 >> 24) as u8,
-            (d1 >> 16) as u8,
-            (d1 >> 8) as u8,
-            d1 as u8,
-            (d2 >> 8) as u8,
-            d2 as u8,
-            (d3 >> 8) as u8,
-            d3 as u8,
-            d4[0],
-            d4[1],
-            d4[2],
-            d4[3],
-            d4[4],
-            d4[5],
-            d4[6],
-            d4[7],
-        ])
+        Uuid::from_fields_endian(d1, d2, d3, d4, Endianness::Big)
     }
 
     /// Creates a UUID from four field values in little-endian order.
@@ -167,15 +163,55 @@ impl Uuid {
     /// );
     /// ```
     pub const fn from_fields_le(d1: u32, d2: u16, d3: u16, d4: &[u8; 8]) -> Uuid {
+        Uuid::from_fields_endian(d1, d2, d3, d4, Endianness::Little)
+    }
+
+    /// Creates a UUID from four field values in the given byte order.
+    ///
+    /// This is the general form of [`Uuid::from_fields`] and
+    /// [`Uuid::from_fields_le`], for when the endianness of the `d1`, `d2`
+    /// and `d3` fields is only known at runtime. The bytes in `d4` are never
+    /// flipped.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::{Endianness, Uuid};
+    /// let d1 = 0xa1a2a3a4;
+    /// let d2 = 0xb1b2;
+    /// let d3 = 0xc1c2;
+    /// let d4 = [0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8];
+    ///
+    /// let uuid = Uuid::from_fields_endian(d1, d2, d3, &d4, Endianness::Little);
+    ///
+    /// assert_eq!(
+    ///     "a4a3a2a1-b2b1-c2c1-d1d2-d3d4d5d6d7d8",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    pub const fn from_fields_endian(
+        d1: u32,
+        d2: u16,
+        d3: u16,
+        d4: &[u8; 8],
+        endian: Endianness,
+    ) -> Uuid {
+        let (d1, d2, d3) = match endian {
+            Endianness::Big => (d1, d2, d3),
+            Endianness::Little => (d1.swap_bytes(), d2.swap_bytes(), d3.swap_bytes()),
+        };
+
         Uuid::from_bytes([
-            d1 as u8,
-            (d1 >> 8) as u8,
-            (d1 >> 16) as u8,
             (d1 >> 24) as u8,
-            (d2) as u8,
+            (d1 >> 16) as u8,
+            (d1 >> 8) as u8,
+            d1 as u8,
             (d2 >> 8) as u8,
-            d3 as u8,
+            d2 as u8,
             (d3 >> 8) as u8,
+            d3 as u8,
             d4[0],
             d4[1],
             d4[2],
@@ -187,8 +223,93 @@ impl Uuid {
         ])
     }
 
+    /// Creates a UUID from four field values, with the last field in a slice.
+    ///
+    /// This is the same as [`Uuid::from_fields`], but the length of `d4` is
+    /// checked at runtime instead of compile time.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `d4` has any length other than 8.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// # use uuid::Uuid;
+    /// let bytes = [0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8, 0xff];
+    ///
+    /// let uuid = Uuid::from_fields_slice(0xa1a2a3a4, 0xb1b2, 0xc1c2, &bytes[..8])?;
+    ///
+    /// assert_eq!(
+    ///     "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    ///
+    /// assert!(Uuid::from_fields_slice(0xa1a2a3a4, 0xb1b2, 0xc1c2, &bytes).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Uuid::from_fields`]: #method.from_fields
+    pub fn from_fields_slice(d1: u32, d2: u16, d3: u16, d4: &[u8]) -> Result<Uuid, Error> {
+        if d4.len() != 8 {
+            return Err(Error(ErrorKind::FieldLength { len: d4.len() }));
+        }
+
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(d4);
+        Ok(Uuid::from_fields(d1, d2, d3, &bytes))
+    }
+
+    /// Creates a UUID from four field values in little-endian order, with the
+    /// last field in a slice.
+    ///
+    /// This is the same as [`Uuid::from_fields_le`], but the length of `d4`
+    /// is checked at runtime instead of compile time.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `d4` has any length other than 8.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// # use uuid::Uuid;
+    /// let d4 = vec![0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8];
+    ///
+    /// let uuid = Uuid::from_fields_le_slice(0xa1a2a3a4, 0xb1b2, 0xc1c2, &d4)?;
+    ///
+    /// assert_eq!(
+    ///     "a4a3a2a1-b2b1-c2c1-d1d2-d3d4d5d6d7d8",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Uuid::from_fields_le`]: #method.from_fields_le
+    pub fn from_fields_le_slice(d1: u32, d2: u16, d3: u16, d4: &[u8]) -> Result<Uuid, Error> {
+        if d4.len() != 8 {
+            return Err(Error(ErrorKind::FieldLength { len: d4.len() }));
+        }
+
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(d4);
+        Ok(Uuid::from_fields_le(d1, d2, d3, &bytes))
+    }
+
     /// Creates a UUID from a 128bit value.
     ///
+    /// This method is the exact inverse of [`Uuid::as_u128`].
+    ///
+    /// [`Uuid::as_u128`]: #method.as_u128
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -268,6 +389,39 @@ impl Uuid {
         ])
     }
 
+    /// Creates a UUID from a 128bit value in the native byte order of the
+    /// target.
+    ///
+    /// The bytes of `v` as they're laid out in memory become the bytes of the
+    /// UUID, so a `u128` read from a `#[repr(C)]` struct or a C `__int128`
+    /// gives back the UUID it was written from. This is the inverse of
+    /// [`Uuid::to_u128_ne`]. The result depends on the target, so it
+    /// shouldn't be used for deserialization. Use [`Uuid::from_u128`] for
+    /// that instead.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let v = u128::from_ne_bytes([
+    ///     0xa1, 0xa2, 0xa3, 0xa4, 0xb1, 0xb2, 0xc1, 0xc2, 0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8,
+    /// ]);
+    ///
+    /// let uuid = Uuid::from_u128_ne(v);
+    ///
+    /// assert_eq!(
+    ///     "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    ///
+    /// [`Uuid::to_u128_ne`]: #method.to_u128_ne
+    pub const fn from_u128_ne(v: u128) -> Self {
+        Uuid::from_bytes(v.to_ne_bytes())
+    }
+
     /// Creates a UUID from two 64bit values.
     ///
     /// # Examples
@@ -307,6 +461,43 @@ impl Uuid {
         ])
     }
 
+    /// Returns an iterator over the UUIDs from `start` up to, but not
+    /// including, `end`.
+    ///
+    /// The UUIDs are treated as 128bit values, as returned by
+    /// [`Uuid::as_u128`], and each UUID in the range is yielded in order. If
+    /// `end` isn't greater than `start` then the range is empty. This is
+    /// useful for generating small, contiguous blocks of keys for tests.
+    ///
+    /// Note that the UUIDs are just consecutive numbers, so most of them
+    /// won't have a valid version or variant.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let start = Uuid::from_u128(0xa1a2a3a4b1b2c1c2d1d2d3d4d5d6d7fe);
+    /// let end = Uuid::from_u128(0xa1a2a3a4b1b2c1c2d1d2d3d4d5d6d801);
+    ///
+    /// let uuids: Vec<String> = Uuid::range(start, end).map(|uuid| uuid.to_string()).collect();
+    ///
+    /// assert_eq!(
+    ///     uuids,
+    ///     [
+    ///         "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7fe",
+    ///         "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7ff",
+    ///         "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d800",
+    ///     ],
+    /// );
+    /// ```
+    ///
+    /// [`Uuid::as_u128`]: #method.as_u128
+    pub fn range(start: Uuid, end: Uuid) -> impl DoubleEndedIterator<Item = Uuid> {
+        (start.as_u128()..end.as_u128()).map(Uuid::from_u128)
+    }
+
     /// Creates a UUID using the supplied bytes.
     ///
     /// # Errors
@@ -450,6 +641,111 @@ impl Uuid {
         ])
     }
 
+    /// Creates a UUID using the supplied bytes in the native byte order of the
+    /// target.
+    ///
+    /// This is the inverse of [`Uuid::to_ne_bytes`]. The result depends on the
+    /// target, so it shouldn't be used for deserialization. Use
+    /// [`Uuid::from_bytes`] for that instead.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let v = 0xa1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8u128;
+    ///
+    /// let uuid = Uuid::from_ne_bytes(v.to_ne_bytes());
+    ///
+    /// assert_eq!(
+    ///     "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    pub const fn from_ne_bytes(b: Bytes) -> Uuid {
+        Uuid::from_u128(u128::from_ne_bytes(b))
+    }
+
+    /// Creates a UUID from four 32bit words in the native byte order of the
+    /// target.
+    ///
+    /// Each word supplies 4 consecutive bytes of the UUID, starting with
+    /// the first, as they're laid out in memory on the current target. This
+    /// is the same as reinterpreting a `[u32; 4]` as the 16 bytes of the UUID,
+    /// which is how hardware often exposes a 128bit value through four 32bit
+    /// registers.
+    ///
+    /// This is the inverse of [`Uuid::as_u32_array_ne`]. The result depends on
+    /// the target: on big-endian targets the first word is `0xa1a2a3a4` for
+    /// the UUID `a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8`, and on little-endian
+    /// targets it's `0xa4a3a2a1`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let words = [
+    ///     u32::from_ne_bytes([0xa1, 0xa2, 0xa3, 0xa4]),
+    ///     u32::from_ne_bytes([0xb1, 0xb2, 0xc1, 0xc2]),
+    ///     u32::from_ne_bytes([0xd1, 0xd2, 0xd3, 0xd4]),
+    ///     u32::from_ne_bytes([0xd5, 0xd6, 0xd7, 0xd8]),
+    /// ];
+    ///
+    /// let uuid = Uuid::from_u32_array_ne(words);
+    ///
+    /// assert_eq!(
+    ///     "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    ///
+    /// [`Uuid::as_u32_array_ne`]: #method.as_u32_array_ne
+    pub const fn from_u32_array_ne(words: [u32; 4]) -> Uuid {
+        let w0 = words[0].to_ne_bytes();
+        let w1 = words[1].to_ne_bytes();
+        let w2 = words[2].to_ne_bytes();
+        let w3 = words[3].to_ne_bytes();
+
+        Uuid::from_bytes([
+            w0[0], w0[1], w0[2], w0[3], w1[0], w1[1], w1[2], w1[3], w2[0], w2[1], w2[2], w2[3],
+            w3[0], w3[1], w3[2], w3[3],
+        ])
+    }
+
+    /// Creates a UUID using the supplied bytes in the mixed-endian layout used
+    /// by GPT partition tables.
+    ///
+    /// The first three fields encoded in the buffer are little-endian and will
+    /// be flipped, the remaining eight bytes are taken as-is. See
+    /// [`Uuid::to_gpt_bytes`] for details on the layout.
+    ///
+    /// This is equivalent to [`Uuid::from_bytes_le`].
+    ///
+    /// # Examples
+    ///
+    /// Reading the EFI System Partition type GUID from disk:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let bytes = [
+    ///     0x28, 0x73, 0x2a, 0xc1, 0x1f, 0xf8, 0xd2, 0x11, 0xba, 0x4b, 0x00, 0xa0,
+    ///     0xc9, 0x3e, 0xc9, 0x3b,
+    /// ];
+    ///
+    /// let uuid = Uuid::from_gpt_bytes(bytes);
+    ///
+    /// assert_eq!(
+    ///     "c12a7328-f81f-11d2-ba4b-00a0c93ec93b",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    pub const fn from_gpt_bytes(b: Bytes) -> Uuid {
+        Uuid::from_bytes_le(b)
+    }
+
     /// Creates a reference to a UUID from a reference to the supplied bytes.
     ///
     /// # Examples
@@ -865,6 +1161,86 @@ impl Builder {
         self
     }
 
+    /// Specifies the low field of the timestamp of the UUID.
+    pub fn set_time_low(&mut self, time_low: u32) -> &mut Self {
+        *self = Builder(self.0).with_time_low(time_low);
+        self
+    }
+
+    /// Specifies the low field of the timestamp of the UUID.
+    ///
+    /// This is the first 4 bytes of the UUID, written in big-endian order.
+    /// Together with [`Builder::with_time_mid`] and
+    /// [`Builder::with_time_high`], it can be used to assemble a time-based
+    /// UUID from its fields in any order.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::{Builder, Variant, Version};
+    /// let uuid = Builder::nil()
+    ///     .with_version(Version::Mac)
+    ///     .with_variant(Variant::RFC4122)
+    ///     .with_time_high(0x1d1)
+    ///     .with_time_mid(0x9dad)
+    ///     .with_time_low(0x6ba7b810)
+    ///     .into_uuid();
+    ///
+    /// assert_eq!(
+    ///     "6ba7b810-9dad-11d1-8000-000000000000",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    ///
+    /// [`Builder::with_time_mid`]: #method.with_time_mid
+    /// [`Builder::with_time_high`]: #method.with_time_high
+    pub const fn with_time_low(mut self, time_low: u32) -> Self {
+        (self.0).0[0] = (time_low >> 24) as u8;
+        (self.0).0[1] = (time_low >> 16) as u8;
+        (self.0).0[2] = (time_low >> 8) as u8;
+        (self.0).0[3] = time_low as u8;
+
+        self
+    }
+
+    /// Specifies the middle field of the timestamp of the UUID.
+    pub fn set_time_mid(&mut self, time_mid: u16) -> &mut Self {
+        *self = Builder(self.0).with_time_mid(time_mid);
+        self
+    }
+
+    /// Specifies the middle field of the timestamp of the UUID.
+    ///
+    /// This is bytes 4 and 5 of the UUID, written in big-endian order.
+    pub const fn with_time_mid(mut self, time_mid: u16) -> Self {
+        (self.0).0[4] = (time_mid >> 8) as u8;
+        (self.0).0[5] = time_mid as u8;
+
+        self
+    }
+
+    /// Specifies the high field of the timestamp of the UUID.
+    pub fn set_time_high(&mut self, time_high: u16) -> &mut Self {
+        *self = Builder(self.0).with_time_high(time_high);
+        self
+    }
+
+    /// Specifies the high field of the timestamp of the UUID.
+    ///
+    /// The low 12 bits of `time_high` are written to bytes 6 and 7 of the
+    /// UUID in big-endian order. The version takes up the other 4 bits of
+    /// the field, so they're kept as they are and the high 4 bits of
+    /// `time_high` are ignored. That means the version can be set before or
+    /// after the timestamp.
+    pub const fn with_time_high(mut self, time_high: u16) -> Self {
+        (self.0).0[6] = ((self.0).0[6] & 0xf0) | ((time_high >> 8) as u8 & 0x0f);
+        (self.0).0[7] = time_high as u8;
+
+        self
+    }
+
     /// Get a reference to the underlying [`Uuid`].
     ///
     /// # Examples
@@ -884,6 +1260,44 @@ impl Builder {
         &self.0
     }
 
+    /// Get a reference to the bytes of the UUID being built.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::{Builder, Version};
+    /// let builder = Builder::nil().with_version(Version::Random);
+    ///
+    /// assert_eq!(0x40, builder.as_bytes()[6]);
+    /// ```
+    pub const fn as_bytes(&self) -> &Bytes {
+        self.0.as_bytes()
+    }
+
+    /// Get a copy of the bytes of the UUID being built.
+    ///
+    /// The builder can still be used afterwards.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::{Builder, Variant};
+    /// let mut builder = Builder::from_bytes([0xff; 16]);
+    ///
+    /// let bytes = builder.bytes();
+    /// builder.set_variant(Variant::RFC4122);
+    ///
+    /// assert_eq!([0xff; 16], bytes);
+    /// assert_eq!(0xbf, builder.as_bytes()[8]);
+    /// ```
+    pub const fn bytes(&self) -> Bytes {
+        self.0.into_bytes()
+    }
+
     /// Convert the builder into a [`Uuid`].
     ///
     /// # Examples
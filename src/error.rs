@@ -1,4 +1,4 @@
-use crate::std::fmt;
+use crate::{parser::strip_urn_prefix, std::fmt, ParseMode};
 
 /// A general error that can occur when working with UUIDs.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -16,10 +16,18 @@ pub(crate) enum ErrorKind {
     SimpleLength { len: usize },
     /// A byte array didn't contain 16 bytes
     ByteLength { len: usize },
+    /// The last field of a [`Uuid`] didn't contain 8 bytes
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    FieldLength { len: usize },
     /// A hyphenated [`Uuid`] didn't contain 5 groups
     ///
     /// [`Uuid`]: ../struct.Uuid.html
-    GroupCount { count: usize },
+    GroupCount {
+        count: usize,
+        total: usize,
+        wrapper_len: usize,
+    },
     /// A hyphenated [`Uuid`] had a group that wasn't the right length
     ///
     /// [`Uuid`]: ../struct.Uuid.html
@@ -27,9 +35,65 @@ pub(crate) enum ErrorKind {
         group: usize,
         len: usize,
         index: usize,
+        total: usize,
+        wrapper_len: usize,
     },
     /// The input was not a valid UTF8 string
     InvalidUTF8,
+    /// A [`Uuid`] string ended with the wrong check character
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    CheckChar { character: char },
+    /// A valid [`Uuid`] string wasn't in the format required by a [`ParseMode`]
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    /// [`ParseMode`]: ../enum.ParseMode.html
+    Mode { mode: ParseMode },
+    /// A string was neither a ULID nor a hyphenated [`Uuid`]
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    UlidLength { len: usize },
+    /// An OID URN didn't start with `urn:oid:2.25.`
+    OidPrefix,
+    /// The last arc of an OID URN wasn't a decimal that fits in a [`Uuid`]
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    OidValue,
+    /// A decimal [`Uuid`] was empty, had more than 39 digits, or was too large
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    DecimalValue,
+    /// A random [`Uuid`] that isn't excluded couldn't be generated
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    #[cfg(all(feature = "v4", feature = "rand_core"))]
+    Excluded { attempts: usize },
+    /// A sequence of words didn't contain 16 words
+    #[cfg(feature = "phonetic")]
+    WordCount { count: usize },
+    /// A word wasn't in the word list for its position
+    #[cfg(feature = "phonetic")]
+    Word { index: usize },
+    /// A version 7 [`Uuid`] counter was wider than the bits available for it
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    #[cfg(all(uuid_unstable, feature = "v7"))]
+    CounterBits { bits: u8 },
+    /// A pool of recorded random bytes ran out before a [`Uuid`] was complete
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    #[cfg(all(uuid_unstable, feature = "v7"))]
+    PoolExhausted { remaining: usize },
+    /// The segments of a custom [`Uuid`] needed more bits than are available
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    #[cfg(all(uuid_unstable, feature = "v8"))]
+    SegmentBits { bits: u32 },
+    /// A segment of a custom [`Uuid`] had a value that didn't fit its width
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    #[cfg(all(uuid_unstable, feature = "v8"))]
+    SegmentValue { index: usize, bits: u32 },
     /// Some other error occurred.
     Other,
 }
@@ -44,6 +108,77 @@ pub(crate) enum ErrorKind {
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct InvalidUuid<'a>(pub(crate) &'a [u8]);
 
+impl Error {
+    /// The length of the input, if it was rejected because it had
+    /// the wrong length.
+    ///
+    /// For strings this is the length of the whole input in bytes,
+    /// including any braces or `urn:uuid:` prefix. For byte slices it's
+    /// the number of bytes in the slice. A hyphenated UUID that has the
+    /// right length overall but a hyphen in the wrong place doesn't report
+    /// a length.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let err = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8abcd").unwrap_err();
+    ///
+    /// assert_eq!(Some(40), err.found_length());
+    /// assert_eq!(
+    ///     "invalid group length in group 4: expected 12, found 16 (expected 36 characters, found 40)",
+    ///     err.to_string(),
+    /// );
+    ///
+    /// let err = Uuid::parse_str("67e55044-10b1-426f-9247").unwrap_err();
+    ///
+    /// assert_eq!(Some(23), err.found_length());
+    ///
+    /// let err = Uuid::parse_str("67e55044-10b-1426f-9247-bb680e5fe0c8").unwrap_err();
+    ///
+    /// assert_eq!(None, err.found_length());
+    /// ```
+    pub const fn found_length(&self) -> Option<usize> {
+        match self.0 {
+            ErrorKind::SimpleLength { len }
+            | ErrorKind::ByteLength { len }
+            | ErrorKind::FieldLength { len }
+            | ErrorKind::UlidLength { len } => Some(len),
+            ErrorKind::GroupCount {
+                total, wrapper_len, ..
+            }
+            | ErrorKind::GroupLength {
+                total, wrapper_len, ..
+            } => hyphenated_length(total, wrapper_len),
+            _ => None,
+        }
+    }
+}
+
+/// The length of a hyphenated input, if it isn't the 36 characters plus any
+/// braces or `urn:uuid:` prefix it should have.
+const fn hyphenated_length(total: usize, wrapper_len: usize) -> Option<usize> {
+    if total == 36 + wrapper_len {
+        None
+    } else {
+        Some(total)
+    }
+}
+
+fn fmt_hyphenated_length(f: &mut fmt::Formatter, total: usize, wrapper_len: usize) -> fmt::Result {
+    match hyphenated_length(total, wrapper_len) {
+        Some(total) => write!(
+            f,
+            " (expected {} characters, found {})",
+            36 + wrapper_len,
+            total
+        ),
+        None => Ok(()),
+    }
+}
+
 impl<'a> InvalidUuid<'a> {
     /// Converts the lightweight error type into detailed diagnostics.
     pub fn into_err(self) -> Error {
@@ -55,12 +190,15 @@ impl<'a> InvalidUuid<'a> {
 
         let (uuid_str, offset, simple) = match input_str.as_bytes() {
             [b'{', s @ .., b'}'] => (s, 1, false),
-            [b'u', b'r', b'n', b':', b'u', b'u', b'i', b'd', b':', s @ ..] => {
-                (s, "urn:uuid:".len(), false)
-            }
-            s => (s, 0, true),
+            s => match strip_urn_prefix(s) {
+                Some(s) => (s, "urn:uuid:".len(), false),
+                None => (s, 0, true),
+            },
         };
 
+        // The number of characters taken by braces or a `urn:uuid:` prefix
+        let wrapper_len = input_str.len() - uuid_str.len();
+
         let mut hyphen_count = 0;
         let mut group_bounds = [0; 4];
 
@@ -82,7 +220,7 @@ impl<'a> InvalidUuid<'a> {
                     group_bounds[hyphen_count] = index;
                 }
                 hyphen_count += 1;
-            } else if !matches!(byte, b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F') {
+            } else if !byte.is_ascii_hexdigit() {
                 // Non-hex char
                 return Error(ErrorKind::Char {
                     character: byte as char,
@@ -103,6 +241,8 @@ impl<'a> InvalidUuid<'a> {
             // 5 groups (4 hyphen splits).
             Error(ErrorKind::GroupCount {
                 count: hyphen_count + 1,
+                total: input_str.len(),
+                wrapper_len,
             })
         } else {
             // There are 5 groups, one of them has an incorrect length
@@ -113,6 +253,8 @@ impl<'a> InvalidUuid<'a> {
                         group: i,
                         len: group_bounds[i] - BLOCK_STARTS[i],
                         index: offset + BLOCK_STARTS[i] + 1,
+                        total: input_str.len(),
+                        wrapper_len,
                     });
                 }
             }
@@ -120,8 +262,10 @@ impl<'a> InvalidUuid<'a> {
             // The last group must be too long
             Error(ErrorKind::GroupLength {
                 group: 4,
-                len: input_str.len() - BLOCK_STARTS[4],
+                len: uuid_str.len() - BLOCK_STARTS[4],
                 index: offset + BLOCK_STARTS[4] + 1,
+                total: input_str.len(),
+                wrapper_len,
             })
         }
     }
@@ -146,18 +290,120 @@ impl fmt::Display for Error {
             ErrorKind::ByteLength { len } => {
                 write!(f, "invalid length: expected 16 bytes, found {}", len)
             }
-            ErrorKind::GroupCount { count } => {
-                write!(f, "invalid group count: expected 5, found {}", count)
+            ErrorKind::FieldLength { len } => {
+                write!(
+                    f,
+                    "invalid length: expected 8 bytes for the last field, found {}",
+                    len
+                )
+            }
+            ErrorKind::GroupCount {
+                count,
+                total,
+                wrapper_len,
+            } => {
+                write!(f, "invalid group count: expected 5, found {}", count)?;
+                fmt_hyphenated_length(f, total, wrapper_len)
             }
-            ErrorKind::GroupLength { group, len, .. } => {
+            ErrorKind::GroupLength {
+                group,
+                len,
+                total,
+                wrapper_len,
+                ..
+            } => {
                 let expected = [8, 4, 4, 4, 12][group];
                 write!(
                     f,
                     "invalid group length in group {}: expected {}, found {}",
                     group, expected, len
-                )
+                )?;
+                fmt_hyphenated_length(f, total, wrapper_len)
             }
             ErrorKind::InvalidUTF8 => write!(f, "non-UTF8 input"),
+            ErrorKind::CheckChar { character } => {
+                write!(f, "invalid check character: found `{}`", character)
+            }
+            ErrorKind::Mode { mode } => {
+                let expected = match mode {
+                    ParseMode::Lenient => "any supported format",
+                    ParseMode::CanonicalOnly => "the lower-case hyphenated format",
+                    ParseMode::HyphenatedOnly => "the hyphenated format",
+                    ParseMode::SimpleOnly => "the simple format",
+                    ParseMode::Braced => "the braced format",
+                    ParseMode::Urn => "the URN format",
+                };
+
+                write!(f, "invalid format: expected {}", expected)
+            }
+            ErrorKind::OidPrefix => write!(f, "invalid OID URN: expected `urn:oid:2.25.`"),
+            ErrorKind::OidValue => {
+                write!(
+                    f,
+                    "invalid OID URN: expected a decimal without leading zeros less than 2^128"
+                )
+            }
+            ErrorKind::DecimalValue => {
+                write!(
+                    f,
+                    "invalid decimal: expected 1 to 39 digits for a value less than 2^128"
+                )
+            }
+            ErrorKind::UlidLength { len } => {
+                write!(
+                    f,
+                    "invalid length: expected length 26 for a ULID or 36 for hyphenated format, found {}",
+                    len
+                )
+            }
+            #[cfg(all(feature = "v4", feature = "rand_core"))]
+            ErrorKind::Excluded { attempts } => {
+                write!(
+                    f,
+                    "failed to generate a UUID that isn't excluded after {} attempts",
+                    attempts
+                )
+            }
+            #[cfg(feature = "phonetic")]
+            ErrorKind::WordCount { count } => {
+                write!(f, "invalid word count: expected 16, found {}", count)
+            }
+            #[cfg(feature = "phonetic")]
+            ErrorKind::Word { index } => {
+                write!(f, "invalid word at {}: not in the word list", index)
+            }
+            #[cfg(all(uuid_unstable, feature = "v7"))]
+            ErrorKind::CounterBits { bits } => {
+                write!(
+                    f,
+                    "invalid counter width: expected at most 74 bits, found {}",
+                    bits
+                )
+            }
+            #[cfg(all(uuid_unstable, feature = "v7"))]
+            ErrorKind::PoolExhausted { remaining } => {
+                write!(
+                    f,
+                    "not enough random bytes: expected at least 10, found {}",
+                    remaining
+                )
+            }
+            #[cfg(all(uuid_unstable, feature = "v8"))]
+            ErrorKind::SegmentBits { bits } => {
+                write!(
+                    f,
+                    "invalid segment widths: expected at most 128 bits, found {}",
+                    bits
+                )
+            }
+            #[cfg(all(uuid_unstable, feature = "v8"))]
+            ErrorKind::SegmentValue { index, bits } => {
+                write!(
+                    f,
+                    "invalid segment value: segment {} doesn't fit in {} bits",
+                    index, bits
+                )
+            }
             ErrorKind::Other => write!(f, "failed to parse a UUID"),
         }
     }
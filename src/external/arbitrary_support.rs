@@ -11,6 +11,10 @@ impl Arbitrary<'_> for Uuid {
 
         Ok(Builder::from_random_bytes(b).into_uuid())
     }
+
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (16, Some(16))
+    }
 }
 
 #[cfg(test)]
@@ -29,6 +33,18 @@ mod tests {
         assert_eq!(Variant::RFC4122, uuid.get_variant());
     }
 
+    #[test]
+    fn test_arbitrary_consumes_16_bytes() {
+        let data = [0xff; 20];
+        let mut bytes = Unstructured::new(&data);
+
+        let uuid = Uuid::arbitrary(&mut bytes).unwrap();
+
+        assert_eq!(4, bytes.len());
+        assert_eq!((16, Some(16)), <Uuid as Arbitrary>::size_hint(0));
+        assert_eq!(Some(Version::Random), uuid.get_version());
+    }
+
     #[test]
     fn test_arbitrary_empty() {
         let mut bytes = Unstructured::new(&[]);
@@ -0,0 +1,282 @@
+use crate::{Builder, Bytes, Uuid};
+
+use rand_core::RngCore;
+
+impl Uuid {
+    /// Creates a random UUID using bytes from a caller-supplied RNG.
+    ///
+    /// This reads 16 bytes from `rng` and sets the version and variant bits
+    /// the same way as [`Uuid::new_v4`], so only 122 of the 128 bits in the
+    /// UUID are actually random. It doesn't touch the operating system's RNG,
+    /// so it works in `no_std` environments with a hardware RNG, and with a
+    /// seeded generator it produces the same UUIDs every time, which is
+    /// useful for test fixtures.
+    ///
+    /// The UUIDs are only as unpredictable as `rng` is. Prefer
+    /// [`Uuid::new_v4`] unless you need control over the source of
+    /// randomness.
+    ///
+    /// Note that usage of this method requires the `rand_core` feature of
+    /// this crate to be enabled. It doesn't need the `v4` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rand_core::{impls, RngCore};
+    /// # use uuid::{Uuid, Version};
+    /// // A (not at all random) generator that's seeded with a fixed value
+    /// struct CountingRng(u64);
+    ///
+    /// impl RngCore for CountingRng {
+    ///     fn next_u32(&mut self) -> u32 {
+    ///         self.next_u64() as u32
+    ///     }
+    ///
+    ///     fn next_u64(&mut self) -> u64 {
+    ///         self.0 += 1;
+    ///         self.0
+    ///     }
+    ///
+    ///     fn fill_bytes(&mut self, dest: &mut [u8]) {
+    ///         impls::fill_bytes_via_next(self, dest)
+    ///     }
+    ///
+    ///     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+    ///         self.fill_bytes(dest);
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let uuid = Uuid::from_rng(&mut CountingRng(0));
+    ///
+    /// assert_eq!(Some(Version::Random), uuid.get_version());
+    /// assert_eq!(uuid, Uuid::from_rng(&mut CountingRng(0)));
+    /// ```
+    ///
+    /// [`Uuid::new_v4`]: #method.new_v4
+    pub fn from_rng<R: RngCore + ?Sized>(rng: &mut R) -> Uuid {
+        let mut bytes = [0; 16];
+        rng.fill_bytes(&mut bytes);
+
+        Builder::from_random_bytes(bytes).into_uuid()
+    }
+
+    /// Creates a random UUID that falls within the inclusive range `lo..=hi`,
+    /// using bytes from a caller-supplied RNG.
+    ///
+    /// The value is sampled uniformly from the range by treating the UUIDs as
+    /// 128bit values, using rejection sampling to avoid any bias towards the
+    /// start of the range. At least 16 bytes are read from `rng` for each
+    /// sample, and more than half of all samples are accepted, so only a
+    /// broken RNG that keeps returning out of range values will make this
+    /// loop for long.
+    ///
+    /// The returned UUID has no version or variant set, it's an arbitrary
+    /// value between `lo` and `hi`. This is useful for generating synthetic
+    /// keys that land in a specific partition of a keyspace.
+    ///
+    /// Note that usage of this method requires the `rand_core` feature of
+    /// this crate to be enabled.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `lo` is greater than `hi`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use rand_core::{impls, RngCore};
+    /// # use uuid::Uuid;
+    /// # struct CountingRng(u64);
+    /// # impl RngCore for CountingRng {
+    /// #     fn next_u32(&mut self) -> u32 { self.next_u64() as u32 }
+    /// #     fn next_u64(&mut self) -> u64 { self.0 += 1; self.0 }
+    /// #     fn fill_bytes(&mut self, dest: &mut [u8]) { impls::fill_bytes_via_next(self, dest) }
+    /// #     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+    /// #         self.fill_bytes(dest);
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// let mut rng = CountingRng(0);
+    ///
+    /// let lo = Uuid::from_u128(0x40000000_0000_0000_0000_000000000000);
+    /// let hi = Uuid::from_u128(0x7fffffff_ffff_ffff_ffff_ffffffffffff);
+    ///
+    /// let uuid = Uuid::random_in_range(lo, hi, &mut rng);
+    ///
+    /// assert!(lo <= uuid && uuid <= hi);
+    /// ```
+    pub fn random_in_range<R: RngCore + ?Sized>(lo: Uuid, hi: Uuid, rng: &mut R) -> Uuid {
+        random_in_range_with(lo, hi, || {
+            let mut bytes = [0; 16];
+            rng.fill_bytes(&mut bytes);
+
+            bytes
+        })
+    }
+}
+
+/// Sample a UUID uniformly from `lo..=hi`, calling `random_bytes` for each sample.
+fn random_in_range_with(lo: Uuid, hi: Uuid, mut random_bytes: impl FnMut() -> Bytes) -> Uuid {
+    assert!(
+        lo <= hi,
+        "the lower bound of the range must not exceed the upper bound"
+    );
+
+    let lo = lo.as_u128();
+    let span = hi.as_u128() - lo;
+
+    if span == 0 {
+        return Uuid::from_u128(lo);
+    }
+
+    // Only draw as many bits as are needed to cover the span,
+    // so at least half of all samples are accepted
+    let mask = u128::MAX >> span.leading_zeros();
+
+    loop {
+        let sample = u128::from_be_bytes(random_bytes()) & mask;
+
+        if sample <= span {
+            return Uuid::from_u128(lo + sample);
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    use crate::{
+        tests::{new, new2, xorshift},
+        Variant, Version,
+    };
+
+    /// An RNG that fills bytes with an incrementing value.
+    pub(crate) struct StepRng(pub(crate) u8);
+
+    impl RngCore for StepRng {
+        fn next_u32(&mut self) -> u32 {
+            rand_core::impls::next_u32_via_fill(self)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            rand_core::impls::next_u64_via_fill(self)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for b in dest {
+                *b = self.0;
+                self.0 = self.0.wrapping_add(1);
+            }
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_from_rng() {
+        let mut rng = StepRng(0);
+
+        let uuid = Uuid::from_rng(&mut rng);
+
+        assert_eq!(Some(Version::Random), uuid.get_version());
+        assert_eq!(Variant::RFC4122, uuid.get_variant());
+        assert_eq!(
+            Uuid::from_u128(0x00010203_0405_4607_8809_0a0b0c0d0e0f),
+            uuid
+        );
+
+        // Exactly 16 bytes were read
+        assert_eq!(16, rng.0);
+    }
+
+    #[test]
+    fn test_from_rng_is_deterministic() {
+        for seed in [0, 1, 0xff] {
+            assert_eq!(
+                Uuid::from_rng(&mut StepRng(seed)),
+                Uuid::from_rng(&mut StepRng(seed))
+            );
+        }
+
+        let mut rng = StepRng(0);
+        assert_ne!(Uuid::from_rng(&mut rng), Uuid::from_rng(&mut rng));
+    }
+
+    #[test]
+    fn test_from_rng_dyn() {
+        let rng: &mut dyn RngCore = &mut StepRng(0);
+
+        assert_eq!(Uuid::from_rng(&mut StepRng(0)), Uuid::from_rng(rng));
+    }
+
+    #[test]
+    fn test_random_in_range() {
+        let lo = Uuid::from_u128(0x40000000_0000_0000_0000_000000000000);
+        let hi = Uuid::from_u128(0x40000000_0000_0000_0000_0000000003e7);
+
+        let mut rng = xorshift(0x9e3779b97f4a7c15f39cc0605cedc834);
+        let mut seen_lo = false;
+        let mut seen_hi = false;
+
+        for _ in 0..100_000 {
+            let uuid = random_in_range_with(lo, hi, &mut rng);
+
+            assert!(lo <= uuid && uuid <= hi, "{} out of range", uuid);
+
+            seen_lo |= uuid == lo;
+            seen_hi |= uuid == hi;
+        }
+
+        // With 1000 possible values both bounds are all but guaranteed to be sampled
+        assert!(seen_lo);
+        assert!(seen_hi);
+    }
+
+    #[test]
+    fn test_random_in_range_rng() {
+        let lo = Uuid::from_u128(0x40000000_0000_0000_0000_000000000000);
+        let hi = Uuid::from_u128(0x40000000_0000_0000_0000_0000000003e7);
+
+        let mut rng = StepRng(0);
+
+        // The first sample, 0x20f, is in range
+        assert_eq!(
+            Uuid::from_u128(0x40000000_0000_0000_0000_00000000020f),
+            Uuid::random_in_range(lo, hi, &mut rng)
+        );
+        assert_eq!(16, rng.0);
+
+        assert_eq!(
+            Uuid::random_in_range(lo, hi, &mut StepRng(7)),
+            Uuid::random_in_range(lo, hi, &mut StepRng(7))
+        );
+    }
+
+    #[test]
+    fn test_random_in_range_single_value() {
+        let uuid = new();
+
+        assert_eq!(Uuid::random_in_range(uuid, uuid, &mut StepRng(0)), uuid);
+    }
+
+    #[test]
+    fn test_random_in_range_full() {
+        assert_eq!(
+            Uuid::random_in_range(Uuid::nil(), Uuid::from_u128(u128::MAX), &mut StepRng(0)),
+            Uuid::from_u128(0x00010203_0405_0607_0809_0a0b0c0d0e0f)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_random_in_range_inverted() {
+        Uuid::random_in_range(new2(), new(), &mut StepRng(0));
+    }
+}
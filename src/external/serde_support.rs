@@ -67,7 +67,7 @@ impl<'de> Deserialize<'de> for Uuid {
                 type Value = Uuid;
 
                 fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                    write!(formatter, "a UUID string")
+                    write!(formatter, "a UUID string or 16 bytes")
                 }
 
                 fn visit_str<E: de::Error>(self, value: &str) -> Result<Uuid, E> {
@@ -78,35 +78,59 @@ impl<'de> Deserialize<'de> for Uuid {
                     Uuid::from_slice(value).map_err(de_error)
                 }
 
+                // Formats like YAML read an unquoted simple UUID that only
+                // contains decimal digits as a number, dropping its leading zeros
+                fn visit_u64<E: de::Error>(self, value: u64) -> Result<Uuid, E> {
+                    let mut digits = [b'0'; 32];
+
+                    let mut value = value;
+                    for digit in digits.iter_mut().rev() {
+                        *digit = b'0' + (value % 10) as u8;
+                        value /= 10;
+                    }
+
+                    Uuid::try_parse_ascii(&digits).map_err(de_error)
+                }
+
+                fn visit_i64<E: de::Error>(self, value: i64) -> Result<Uuid, E> {
+                    if value < 0 {
+                        return Err(E::invalid_value(de::Unexpected::Signed(value), &self));
+                    }
+
+                    self.visit_u64(value as u64)
+                }
+
                 fn visit_seq<A>(self, mut seq: A) -> Result<Uuid, A::Error>
                 where
                     A: de::SeqAccess<'vi>,
                 {
-                    #[rustfmt::skip]
-                    let bytes = [
-                        match seq.next_element()? { Some(e) => e, None => return Err(A::Error::invalid_length(16, &self)) },
-                        match seq.next_element()? { Some(e) => e, None => return Err(A::Error::invalid_length(16, &self)) },
-                        match seq.next_element()? { Some(e) => e, None => return Err(A::Error::invalid_length(16, &self)) },
-                        match seq.next_element()? { Some(e) => e, None => return Err(A::Error::invalid_length(16, &self)) },
-                        match seq.next_element()? { Some(e) => e, None => return Err(A::Error::invalid_length(16, &self)) },
-                        match seq.next_element()? { Some(e) => e, None => return Err(A::Error::invalid_length(16, &self)) },
-                        match seq.next_element()? { Some(e) => e, None => return Err(A::Error::invalid_length(16, &self)) },
-                        match seq.next_element()? { Some(e) => e, None => return Err(A::Error::invalid_length(16, &self)) },
-                        match seq.next_element()? { Some(e) => e, None => return Err(A::Error::invalid_length(16, &self)) },
-                        match seq.next_element()? { Some(e) => e, None => return Err(A::Error::invalid_length(16, &self)) },
-                        match seq.next_element()? { Some(e) => e, None => return Err(A::Error::invalid_length(16, &self)) },
-                        match seq.next_element()? { Some(e) => e, None => return Err(A::Error::invalid_length(16, &self)) },
-                        match seq.next_element()? { Some(e) => e, None => return Err(A::Error::invalid_length(16, &self)) },
-                        match seq.next_element()? { Some(e) => e, None => return Err(A::Error::invalid_length(16, &self)) },
-                        match seq.next_element()? { Some(e) => e, None => return Err(A::Error::invalid_length(16, &self)) },
-                        match seq.next_element()? { Some(e) => e, None => return Err(A::Error::invalid_length(16, &self)) },
-                    ];
+                    let mut bytes = [0; 16];
+
+                    for (len, byte) in bytes.iter_mut().enumerate() {
+                        *byte = match seq.next_element()? {
+                            Some(e) => e,
+                            None => return Err(A::Error::invalid_length(len, &self)),
+                        };
+                    }
+
+                    // Count any extra elements so the error can say how many there were
+                    if seq.next_element::<de::IgnoredAny>()?.is_some() {
+                        let mut len = 17;
+                        while seq.next_element::<de::IgnoredAny>()?.is_some() {
+                            len += 1;
+                        }
+
+                        return Err(A::Error::invalid_length(len, &self));
+                    }
 
                     Ok(Uuid::from_bytes(bytes))
                 }
             }
 
-            deserializer.deserialize_str(UuidVisitor)
+            // Self-describing formats like JSON need to be asked for any
+            // type, or they'll refuse to hand a sequence of bytes to a
+            // visitor that was asked for a string
+            deserializer.deserialize_any(UuidVisitor)
         } else {
             struct UuidBytesVisitor;
 
@@ -130,6 +154,21 @@ impl<'de> Deserialize<'de> for Uuid {
 pub mod compact {
     //! Serialize a [`Uuid`] as a `[u8; 16]`.
     //!
+    //! The bytes are used even in human-readable formats, where a `Uuid`
+    //! would usually be serialized as a string.
+    //!
+    //! # Examples
+    //!
+    //! ```
+    //! # use serde_derive::{Deserialize, Serialize};
+    //! # use uuid::Uuid;
+    //! #[derive(Serialize, Deserialize)]
+    //! struct Item {
+    //!     #[serde(with = "uuid::serde::compact")]
+    //!     id: Uuid,
+    //! }
+    //! ```
+    //!
     //! [`Uuid`]: ../../struct.Uuid.html
 
     /// Serialize from a [`Uuid`] as a `[u8; 16]`
@@ -207,6 +246,115 @@ pub mod compact {
     }
 }
 
+/// Deserialize a [`Uuid`] from a string, even if the format isn't human-readable.
+fn deserialize_str<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Uuid, D::Error> {
+    struct UuidStrVisitor;
+
+    impl<'vi> de::Visitor<'vi> for UuidStrVisitor {
+        type Value = Uuid;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(formatter, "a UUID string")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Uuid, E> {
+            value
+                .parse::<Uuid>()
+                .map_err(|e| E::custom(format_args!("UUID parsing failed: {}", e)))
+        }
+    }
+
+    deserializer.deserialize_str(UuidStrVisitor)
+}
+
+pub mod simple {
+    //! Serialize a [`Uuid`] as a simple string, like
+    //! `67e5504410b1426f9247bb680e5fe0c8`.
+    //!
+    //! The string is used even in formats that aren't human-readable, where
+    //! a `Uuid` would usually be serialized as bytes. Any string that
+    //! [`Uuid::parse_str`] accepts can be deserialized.
+    //!
+    //! # Examples
+    //!
+    //! ```
+    //! # use serde_derive::{Deserialize, Serialize};
+    //! # use uuid::Uuid;
+    //! #[derive(Serialize, Deserialize)]
+    //! struct Item {
+    //!     #[serde(with = "uuid::serde::simple")]
+    //!     id: Uuid,
+    //! }
+    //! ```
+    //!
+    //! [`Uuid`]: ../../struct.Uuid.html
+    //! [`Uuid::parse_str`]: ../../struct.Uuid.html#method.parse_str
+
+    /// Serialize a [`Uuid`] as a simple string.
+    ///
+    /// [`Uuid`]: ../../struct.Uuid.html
+    pub fn serialize<S>(u: &crate::Uuid, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(u.as_simple(), serializer)
+    }
+
+    /// Deserialize a string as a [`Uuid`].
+    ///
+    /// [`Uuid`]: ../../struct.Uuid.html
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<crate::Uuid, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        super::deserialize_str(deserializer)
+    }
+}
+
+pub mod braced {
+    //! Serialize a [`Uuid`] as a braced string, like
+    //! `{67e55044-10b1-426f-9247-bb680e5fe0c8}`.
+    //!
+    //! The string is used even in formats that aren't human-readable, where
+    //! a `Uuid` would usually be serialized as bytes. Any string that
+    //! [`Uuid::parse_str`] accepts can be deserialized.
+    //!
+    //! # Examples
+    //!
+    //! ```
+    //! # use serde_derive::{Deserialize, Serialize};
+    //! # use uuid::Uuid;
+    //! #[derive(Serialize, Deserialize)]
+    //! struct Item {
+    //!     #[serde(with = "uuid::serde::braced")]
+    //!     id: Uuid,
+    //! }
+    //! ```
+    //!
+    //! [`Uuid`]: ../../struct.Uuid.html
+    //! [`Uuid::parse_str`]: ../../struct.Uuid.html#method.parse_str
+
+    /// Serialize a [`Uuid`] as a braced string.
+    ///
+    /// [`Uuid`]: ../../struct.Uuid.html
+    pub fn serialize<S>(u: &crate::Uuid, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(u.as_braced(), serializer)
+    }
+
+    /// Deserialize a string as a [`Uuid`].
+    ///
+    /// [`Uuid`]: ../../struct.Uuid.html
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<crate::Uuid, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        super::deserialize_str(deserializer)
+    }
+}
+
 #[cfg(test)]
 mod serde_tests {
     use super::*;
@@ -298,6 +446,156 @@ mod serde_tests {
         );
     }
 
+    #[test]
+    fn test_roundtrip_json() {
+        let u = Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4").unwrap();
+
+        let json = serde_json::to_string(&u).unwrap();
+        assert_eq!("\"f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4\"", json);
+        assert_eq!(u, serde_json::from_str::<Uuid>(&json).unwrap());
+
+        // Any format accepted by the lenient parser can be read back
+        assert_eq!(
+            u,
+            serde_json::from_str::<Uuid>("\"{F9168C5E-CEB2-4FAA-B6BF-329BF39FA1E4}\"").unwrap()
+        );
+
+        // Including a simple UUID that looks like a number
+        assert_eq!(
+            Uuid::from_u128(0x12345678_1234_5678_1234_567812345678),
+            serde_json::from_str::<Uuid>("\"12345678123456781234567812345678\"").unwrap()
+        );
+
+        // As well as the bytes of the UUID
+        let bytes = serde_json::to_string(u.as_bytes()).unwrap();
+        assert_eq!(u, serde_json::from_str::<Uuid>(&bytes).unwrap());
+    }
+
+    #[test]
+    fn test_de_number() {
+        // A simple UUID of decimal digits that lost its quotes, and its leading zeros
+        assert_eq!(
+            Uuid::from_u128(0x00000000_0000_0000_0000_001234567890),
+            serde_json::from_str::<Uuid>("1234567890").unwrap()
+        );
+
+        serde_test::assert_de_tokens(
+            &Uuid::from_u128(0x00000000_0000_1844_6744_073709551615).readable(),
+            &[Token::U64(u64::MAX)],
+        );
+        serde_test::assert_de_tokens(
+            &Uuid::from_u128(0x00000000_0000_0000_0000_000000000042).readable(),
+            &[Token::I64(42)],
+        );
+        serde_test::assert_de_tokens_error::<Readable<Uuid>>(
+            &[Token::I64(-1)],
+            "invalid value: integer `-1`, expected a UUID string or 16 bytes",
+        );
+
+        // Numbers too large for an integer can't be recovered
+        assert!(serde_json::from_str::<Uuid>("12345678123456781234567812345678").is_err());
+    }
+
+    #[test]
+    fn test_roundtrip_bincode() {
+        let u = Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4").unwrap();
+
+        let encoded = bincode::serialize(&u).unwrap();
+
+        // The 16 bytes of the UUID, prefixed with their length
+        assert_eq!(8 + 16, encoded.len());
+        assert_eq!(u.as_bytes(), &encoded[8..]);
+
+        assert_eq!(u, bincode::deserialize::<Uuid>(&encoded).unwrap());
+    }
+
+    #[test]
+    fn test_de_wrong_length() {
+        use crate::std::string::ToString;
+
+        let err = serde_json::from_str::<Uuid>("[1, 2, 3]").unwrap_err();
+        assert_eq!(
+            "invalid length 3, expected a UUID string or 16 bytes at line 1 column 9",
+            err.to_string()
+        );
+
+        let json = serde_json::to_string(&[0u8; 18][..]).unwrap();
+        let err = serde_json::from_str::<Uuid>(&json).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("invalid length 18, expected a UUID string or 16 bytes"),
+            "{}",
+            err
+        );
+
+        serde_test::assert_de_tokens_error::<Readable<Uuid>>(
+            &[
+                Token::Seq { len: Some(3) },
+                Token::U8(1),
+                Token::U8(2),
+                Token::U8(3),
+                Token::SeqEnd,
+            ],
+            "invalid length 3, expected a UUID string or 16 bytes",
+        );
+
+        let mut tokens = vec![Token::Seq { len: Some(18) }];
+        tokens.extend((0..18).map(Token::U8));
+        tokens.push(Token::SeqEnd);
+        serde_test::assert_de_tokens_error::<Readable<Uuid>>(
+            &tokens,
+            "invalid length 18, expected a UUID string or 16 bytes",
+        );
+
+        let mut encoded = bincode::serialize(&[0u8; 15][..]).unwrap();
+        let err = bincode::deserialize::<Uuid>(&encoded).unwrap_err();
+        assert_eq!(
+            "UUID parsing failed: invalid length: expected 16 bytes, found 15",
+            err.to_string()
+        );
+
+        encoded = bincode::serialize(&[0u8; 17][..]).unwrap();
+        assert!(bincode::deserialize::<Uuid>(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_serde_with_string_formats() {
+        use serde_derive::*;
+
+        #[derive(Clone, Copy, Serialize, Debug, Deserialize, PartialEq)]
+        struct UuidContainer {
+            #[serde(with = "crate::serde::simple")]
+            simple: Uuid,
+            #[serde(with = "crate::serde::braced")]
+            braced: Uuid,
+        }
+
+        let u = Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4").unwrap();
+        let container = UuidContainer {
+            simple: u,
+            braced: u,
+        };
+
+        let tokens = [
+            Token::Struct {
+                name: "UuidContainer",
+                len: 2,
+            },
+            Token::Str("simple"),
+            Token::Str("f9168c5eceb24faab6bf329bf39fa1e4"),
+            Token::Str("braced"),
+            Token::Str("{f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4}"),
+            Token::StructEnd,
+        ];
+
+        // The strings are used whether the format is human-readable or not
+        serde_test::assert_tokens(&container.readable(), &tokens);
+        serde_test::assert_tokens(&container.compact(), &tokens);
+
+        let encoded = bincode::serialize(&container).unwrap();
+        assert_eq!(container, bincode::deserialize(&encoded).unwrap());
+    }
+
     #[test]
     fn test_de_failure() {
         serde_test::assert_de_tokens_error::<Readable<Uuid>>(
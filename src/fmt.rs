@@ -13,7 +13,7 @@
 
 use crate::{
     std::{borrow::Borrow, fmt, ptr, str},
-    Uuid, Variant,
+    Uuid, UuidInfo, Variant,
 };
 
 impl std::fmt::Debug for Uuid {
@@ -40,6 +40,36 @@ impl fmt::Display for Variant {
     }
 }
 
+impl fmt::Display for UuidInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.version {
+            Some(version) => writeln!(f, "version: {:?}", version)?,
+            None => writeln!(f, "version: unknown")?,
+        }
+
+        writeln!(f, "variant: {}", self.variant)?;
+        writeln!(f, "nil: {}", self.is_nil)?;
+        writeln!(f, "max: {}", self.is_max)?;
+
+        match self.timestamp {
+            Some(ts) => {
+                let (secs, nanos) = ts.to_unix();
+                writeln!(f, "timestamp: {}.{:09}", secs, nanos)?;
+            }
+            None => writeln!(f, "timestamp: none")?,
+        }
+
+        match self.node_id {
+            Some([a, b, c, d, e, g]) => write!(
+                f,
+                "node id: {:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
+                a, b, c, d, e, g
+            ),
+            None => write!(f, "node id: none"),
+        }
+    }
+}
+
 impl fmt::LowerHex for Uuid {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if f.alternate() {
@@ -85,7 +115,157 @@ pub struct Urn(Uuid);
 #[repr(transparent)]
 pub struct Braced(Uuid);
 
+/// A string format for a [`Uuid`].
+///
+/// This can be passed to [`Uuid::format`] or [`Uuid::encode`] to pick the
+/// format at runtime. Each variant has a corresponding formatter type in
+/// this module.
+///
+/// [`Uuid::format`]: ../struct.Uuid.html#method.format
+/// [`Uuid::encode`]: ../struct.Uuid.html#method.encode
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Format {
+    /// A hyphenated string, like `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+    Hyphenated,
+    /// A simple string, like `67e5504410b1426f9247bb680e5fe0c8`.
+    Simple,
+    /// A URN string, like `urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8`.
+    Urn,
+    /// A braced hyphenated string, like `{67e55044-10b1-426f-9247-bb680e5fe0c8}`.
+    Braced,
+}
+
+/// The case of the hex digits in a formatted [`Uuid`].
+///
+/// [`Uuid`]: ../struct.Uuid.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Case {
+    /// Lower-case hex digits, like `a-f` (default).
+    Lower,
+    /// Upper-case hex digits, like `A-F`.
+    Upper,
+}
+
+/// Format a [`Uuid`] in a [`Format`] picked at runtime.
+///
+/// This is returned by [`Uuid::format`].
+///
+/// [`Uuid::format`]: ../struct.Uuid.html#method.format
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FormattedUuid {
+    uuid: Uuid,
+    format: Format,
+}
+
+impl FormattedUuid {
+    /// Get a reference to the underlying [`Uuid`].
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.uuid
+    }
+
+    /// Get the [`Format`] the [`Uuid`] will be written in.
+    pub const fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Consumes the [`FormattedUuid`], returning the underlying [`Uuid`].
+    pub const fn into_uuid(self) -> Uuid {
+        self.uuid
+    }
+}
+
+impl fmt::Display for FormattedUuid {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+impl fmt::LowerHex for FormattedUuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.uuid.encode(f, self.format, Case::Lower)
+    }
+}
+
+impl fmt::UpperHex for FormattedUuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.uuid.encode(f, self.format, Case::Upper)
+    }
+}
+
 impl Uuid {
+    /// Get a [`FormattedUuid`] formatter for a [`Format`] picked at runtime.
+    ///
+    /// This avoids matching over the formatter types when the format comes
+    /// from configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::{fmt::Format, Uuid};
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("936DA01F9ABD4d9d80C702AF85C822A8")?;
+    ///
+    ///     let format = Format::Urn;
+    ///
+    ///     assert_eq!(
+    ///         format!("{}", uuid.format(format)),
+    ///         "urn:uuid:936da01f-9abd-4d9d-80c7-02af85c822a8"
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[inline]
+    pub const fn format(self, format: Format) -> FormattedUuid {
+        FormattedUuid { uuid: self, format }
+    }
+
+    /// Writes the [`Uuid`] to `w` in the given format and case.
+    ///
+    /// This is the primitive that the [`fmt::Display`], [`fmt::LowerHex`]
+    /// and [`fmt::UpperHex`] implementations of all the formatters in this
+    /// module use. It can be used to write a UUID into any [`fmt::Write`]
+    /// when the format isn't known until runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::{
+    ///     fmt::{Case, Format},
+    ///     Uuid,
+    /// };
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("936DA01F9ABD4d9d80C702AF85C822A8")?;
+    ///
+    ///     let mut s = String::new();
+    ///     uuid.encode(&mut s, Format::Braced, Case::Upper).unwrap();
+    ///
+    ///     assert_eq!(s, "{936DA01F-9ABD-4D9D-80C7-02AF85C822A8}");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Uuid`]: ../struct.Uuid.html
+    pub fn encode<W: fmt::Write>(&self, w: &mut W, format: Format, case: Case) -> fmt::Result {
+        let src = self.as_bytes();
+        let upper = case == Case::Upper;
+
+        let mut buf = [0; Urn::LENGTH];
+        let encoded = match format {
+            Format::Hyphenated => encode_hyphenated(src, &mut buf, upper),
+            Format::Simple => encode_simple(src, &mut buf, upper),
+            Format::Urn => encode_urn(src, &mut buf, upper),
+            Format::Braced => encode_braced(src, &mut buf, upper),
+        };
+
+        w.write_str(encoded)
+    }
+
     /// Get a [`Hyphenated`] formatter.
     #[inline]
     pub const fn hyphenated(self) -> Hyphenated {
@@ -100,6 +280,29 @@ impl Uuid {
     }
 
     /// Get a [`Simple`] formatter.
+    ///
+    /// The simple format is 32 hex digits without any hyphens, which fits
+    /// more easily into places like URLs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// assert_eq!("67e5504410b1426f9247bb680e5fe0c8", uuid.simple().to_string());
+    /// assert_eq!(
+    ///     "67e5504410b1426f9247bb680e5fe0c8",
+    ///     format!("{:x}", uuid.simple())
+    /// );
+    /// assert_eq!(
+    ///     "67E5504410B1426F9247BB680E5FE0C8",
+    ///     format!("{:X}", uuid.simple())
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
     #[inline]
     pub const fn simple(self) -> Simple {
         Simple(self)
@@ -113,6 +316,33 @@ impl Uuid {
     }
 
     /// Get a [`Urn`] formatter.
+    ///
+    /// The URN format is the hyphenated format with a `urn:uuid:` prefix.
+    /// [`Urn::LENGTH`] is the exact length of the output, so a buffer for it
+    /// can live on the stack.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use uuid::{fmt::Urn, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// let mut buf = [0; Urn::LENGTH];
+    ///
+    /// assert_eq!(
+    ///     "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+    ///     uuid.urn().encode_lower(&mut buf)
+    /// );
+    /// assert_eq!(
+    ///     "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+    ///     uuid.urn().to_string()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Urn::LENGTH`]: fmt/struct.Urn.html#associatedconstant.LENGTH
     #[inline]
     pub const fn urn(self) -> Urn {
         Urn(self)
@@ -126,6 +356,32 @@ impl Uuid {
     }
 
     /// Get a [`Braced`] formatter.
+    ///
+    /// The braced format is the hyphenated format wrapped in `{}`, which is
+    /// how GUIDs are usually written on Windows. The output is written
+    /// straight into the formatter or buffer, so no intermediate string is
+    /// needed to add the braces.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use uuid::{fmt::Braced, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// let mut buf = [0; Braced::LENGTH];
+    ///
+    /// assert_eq!(
+    ///     "{67E55044-10B1-426F-9247-BB680E5FE0C8}",
+    ///     uuid.braced().encode_upper(&mut buf)
+    /// );
+    /// assert_eq!(
+    ///     "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+    ///     uuid.braced().to_string()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
     #[inline]
     pub const fn braced(self) -> Braced {
         Braced(self)
@@ -137,6 +393,309 @@ impl Uuid {
         // SAFETY: `Uuid` and `Braced` have the same ABI
         unsafe { &*(self as *const Uuid as *const Braced) }
     }
+
+    /// Returns the [`Uuid`] as a lower-case hyphenated string followed by its
+    /// [`Uuid::check_char`].
+    ///
+    /// [`Uuid::check_char`]: struct.Uuid.html#method.check_char
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    ///     assert_eq!(
+    ///         uuid.with_check_char(),
+    ///         "67e55044-10b1-426f-9247-bb680e5fe0c8A"
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn with_check_char(&self) -> std::string::String {
+        let mut buf = [0; Hyphenated::LENGTH + 1];
+
+        self.as_hyphenated().encode_lower(&mut buf);
+        buf[Hyphenated::LENGTH] = self.check_char() as u8;
+
+        // SAFETY: The encoded buffer is ASCII encoded
+        unsafe { str::from_utf8_unchecked(&buf) }.into()
+    }
+
+    /// Returns the first group of the [`Uuid`] as a lower-case string, to use as
+    /// a short handle for display.
+    ///
+    /// The first group is 8 hex digits, or 32 bits. Short handles are only
+    /// practical for small collections: by the birthday bound, there's a 50%
+    /// chance that two of 77,000 random UUIDs share the same one. Use
+    /// [`Uuid::matches_short`] to look a UUID up by its short handle, and
+    /// handle more than one match.
+    ///
+    /// [`Uuid::matches_short`]: #method.matches_short
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("67E55044-10B1-426F-9247-BB680E5FE0C8")?;
+    ///
+    ///     assert_eq!(uuid.short(), "67e55044");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn short(&self) -> std::string::String {
+        let mut buf = [0; Simple::LENGTH];
+
+        self.encode_simple_lower(&mut buf)[..8].into()
+    }
+
+    /// Returns an abbreviated lower-case string of the [`Uuid`] for log lines,
+    /// like `67e55044…e0c8`.
+    ///
+    /// The string is the first 8 and the last 4 hex digits of the UUID,
+    /// joined by a horizontal ellipsis (`…`, U+2026). That's 48 of the 128
+    /// bits, which is usually enough to tell UUIDs apart by eye. The form is
+    /// meant to be read by people and can't be parsed back into a UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    ///     assert_eq!(uuid.compact(), "67e55044…e0c8");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn compact(&self) -> std::string::String {
+        let mut buf = [0; Simple::LENGTH];
+        let simple = self.encode_simple_lower(&mut buf);
+
+        let mut compact = std::string::String::with_capacity(8 + '…'.len_utf8() + 4);
+        compact.push_str(&simple[..8]);
+        compact.push('…');
+        compact.push_str(&simple[28..]);
+
+        compact
+    }
+
+    /// Returns the [`Uuid`] as an OID in the `2.25` arc, like
+    /// `2.25.138101147531900207301164854559698313416`.
+    ///
+    /// ITU-T X.667 assigns every UUID an OID made of the arc `2.25` followed
+    /// by the UUID as a single unsigned decimal integer. Prefixing the OID
+    /// with `urn:oid:` gives a URN that can be parsed back with
+    /// [`Uuid::from_urn_oid`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    ///     assert_eq!(
+    ///         uuid.to_oid_string(),
+    ///         "2.25.138101147531900207301164854559698313416"
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [ITU-T X.667](https://www.itu.int/rec/T-REC-X.667)
+    ///
+    /// [`Uuid::from_urn_oid`]: #method.from_urn_oid
+    #[cfg(feature = "std")]
+    pub fn to_oid_string(&self) -> std::string::String {
+        std::format!("2.25.{}", self.as_u128())
+    }
+
+    /// Returns the [`Uuid`] as a decimal integer, zero-padded to 39 digits.
+    ///
+    /// This is the value of [`Uuid::as_u128`] in base 10, for systems that
+    /// can only store UUIDs as numbers. Every UUID fits in 39 digits, so the
+    /// padded strings all have the same length and sort in the same order as
+    /// the UUIDs themselves. They can be parsed back with
+    /// [`Uuid::from_decimal_string`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    ///     assert_eq!(
+    ///         uuid.to_decimal_string(),
+    ///         "138101147531900207301164854559698313416"
+    ///     );
+    ///     assert_eq!(
+    ///         Uuid::from_u128(1).to_decimal_string(),
+    ///         "000000000000000000000000000000000000001"
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// [`Uuid::as_u128`]: #method.as_u128
+    /// [`Uuid::from_decimal_string`]: #method.from_decimal_string
+    #[cfg(feature = "std")]
+    pub fn to_decimal_string(&self) -> std::string::String {
+        std::format!("{:039}", self.as_u128())
+    }
+
+    /// Checks whether `short` is an abbreviation of the [`Uuid`].
+    ///
+    /// This returns `true` if `short` is a non-empty prefix of the
+    /// hyphenated form of the UUID, ignoring case. Handles returned by
+    /// [`Uuid::short`] always match, but longer prefixes like
+    /// `67e55044-10b1` can be used to tell apart UUIDs whose short handles
+    /// collide.
+    ///
+    /// [`Uuid::short`]: #method.short
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    ///     assert!(uuid.matches_short("67e55044"));
+    ///     assert!(uuid.matches_short("67E550"));
+    ///     assert!(uuid.matches_short("67e55044-10b1"));
+    ///
+    ///     assert!(!uuid.matches_short("67e55045"));
+    ///     assert!(!uuid.matches_short(""));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn matches_short(&self, short: &str) -> bool {
+        let mut buf = [0; Hyphenated::LENGTH];
+        let hyphenated = self.as_hyphenated().encode_lower(&mut buf);
+
+        !short.is_empty()
+            && short.len() <= hyphenated.len()
+            && hyphenated.as_bytes()[..short.len()].eq_ignore_ascii_case(short.as_bytes())
+    }
+
+    /// Checks whether `prefix` is the start of the [`Uuid`] in either the
+    /// hyphenated or simple form, ignoring case.
+    ///
+    /// This is more forgiving than [`Uuid::matches_short`], so it can be used
+    /// to resolve partial IDs typed by people. Each hyphen in `prefix` is
+    /// optional, so `67e5504410b1` and `67e55044-10b1` both match, as does a
+    /// mix like `67e55044-10b1426f`. Hyphens are still only accepted between
+    /// the groups of the hyphenated form, though, so `67e5-5044` doesn't
+    /// match. An empty prefix never matches.
+    ///
+    /// [`Uuid::matches_short`]: #method.matches_short
+    ///
+    /// # Examples
+    ///
+    /// Resolving a partial ID:
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let ids = [
+    ///         Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?,
+    ///         Uuid::parse_str("67e55044-20b1-426f-9247-bb680e5fe0c8")?,
+    ///     ];
+    ///
+    ///     // Ambiguous
+    ///     assert_eq!(2, ids.iter().filter(|id| id.prefix_matches("67E55044")).count());
+    ///
+    ///     // Unique
+    ///     assert_eq!(1, ids.iter().filter(|id| id.prefix_matches("67e5504410")).count());
+    ///     assert_eq!(1, ids.iter().filter(|id| id.prefix_matches("67e55044-2")).count());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn prefix_matches(&self, prefix: &str) -> bool {
+        let mut buf = [0; Simple::LENGTH];
+        let simple = self.encode_simple_lower(&mut buf).as_bytes();
+
+        // The number of hex digits matched so far
+        let mut digits = 0;
+        let mut after_hyphen = false;
+
+        for &b in prefix.as_bytes() {
+            if b == b'-' {
+                if after_hyphen || !matches!(digits, 8 | 12 | 16 | 20) {
+                    return false;
+                }
+
+                after_hyphen = true;
+            } else {
+                if digits == simple.len() || b.to_ascii_lowercase() != simple[digits] {
+                    return false;
+                }
+
+                digits += 1;
+                after_hyphen = false;
+            }
+        }
+
+        digits > 0
+    }
+
+    /// Writes the [`Uuid`] as a lower-case simple string to `buffer`, and
+    /// returns the encoded UUID.
+    ///
+    /// This is like [`Simple::encode_lower`], but because `buffer` is
+    /// exactly [`Simple::LENGTH`] bytes it can't be too small, so there's
+    /// no length check and this method never panics.
+    ///
+    /// [`Simple::encode_lower`]: fmt/struct.Simple.html#method.encode_lower
+    /// [`Simple::LENGTH`]: fmt/struct.Simple.html#associatedconstant.LENGTH
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use uuid::Uuid;
+    ///
+    /// fn main() -> Result<(), uuid::Error> {
+    ///     let uuid = Uuid::parse_str("936DA01F-9ABD-4D9D-80C7-02AF85C822A8")?;
+    ///
+    ///     let mut buf = [0; 32];
+    ///     assert_eq!(
+    ///         uuid.encode_simple_lower(&mut buf),
+    ///         "936da01f9abd4d9d80c702af85c822a8"
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[inline]
+    pub fn encode_simple_lower<'buf>(&self, buffer: &'buf mut [u8; 32]) -> &'buf mut str {
+        *buffer = format_simple(self.as_bytes(), false);
+
+        // SAFETY: The encoded buffer is ASCII encoded
+        unsafe { str::from_utf8_unchecked_mut(buffer) }
+    }
 }
 
 const UPPER: [u8; 16] = [
@@ -187,9 +746,25 @@ const fn format_hyphenated(src: &[u8; 16], upper: bool) -> [u8; 36] {
     dst
 }
 
+/// Get the first `len` bytes of `buffer` to encode a UUID into.
+///
+/// This panics with a message that says how big the buffer needed to be,
+/// rather than the generic out of bounds message from slicing it.
+#[inline]
+fn encode_prefix(buffer: &mut [u8], len: usize) -> &mut [u8] {
+    assert!(
+        buffer.len() >= len,
+        "the buffer is too small to encode a UUID: expected at least {} bytes, found {}",
+        len,
+        buffer.len()
+    );
+
+    &mut buffer[..len]
+}
+
 #[inline]
 fn encode_simple<'b>(src: &[u8; 16], buffer: &'b mut [u8], upper: bool) -> &'b mut str {
-    let buf = &mut buffer[..Simple::LENGTH];
+    let buf = encode_prefix(buffer, Simple::LENGTH);
     let dst = buf.as_mut_ptr();
 
     // SAFETY: `buf` is guaranteed to be at least `LEN` bytes
@@ -202,7 +777,7 @@ fn encode_simple<'b>(src: &[u8; 16], buffer: &'b mut [u8], upper: bool) -> &'b m
 
 #[inline]
 fn encode_hyphenated<'b>(src: &[u8; 16], buffer: &'b mut [u8], upper: bool) -> &'b mut str {
-    let buf = &mut buffer[..Hyphenated::LENGTH];
+    let buf = encode_prefix(buffer, Hyphenated::LENGTH);
     let dst = buf.as_mut_ptr();
 
     // SAFETY: `buf` is guaranteed to be at least `LEN` bytes
@@ -215,7 +790,7 @@ fn encode_hyphenated<'b>(src: &[u8; 16], buffer: &'b mut [u8], upper: bool) -> &
 
 #[inline]
 fn encode_braced<'b>(src: &[u8; 16], buffer: &'b mut [u8], upper: bool) -> &'b mut str {
-    let buf = &mut buffer[..Braced::LENGTH];
+    let buf = encode_prefix(buffer, Braced::LENGTH);
     buf[0] = b'{';
     buf[Braced::LENGTH - 1] = b'}';
 
@@ -231,7 +806,7 @@ fn encode_braced<'b>(src: &[u8; 16], buffer: &'b mut [u8], upper: bool) -> &'b m
 
 #[inline]
 fn encode_urn<'b>(src: &[u8; 16], buffer: &'b mut [u8], upper: bool) -> &'b mut str {
-    let buf = &mut buffer[..Urn::LENGTH];
+    let buf = encode_prefix(buffer, Urn::LENGTH);
     buf[..9].copy_from_slice(b"urn:uuid:");
 
     // SAFETY: `buf` is guaranteed to be at least `LEN` bytes
@@ -838,13 +1413,13 @@ macro_rules! impl_fmt_traits {
 
         impl<$($a),*> fmt::LowerHex for $T<$($a),*> {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                f.write_str(self.encode_lower(&mut [0; Self::LENGTH]))
+                self.0.encode(f, Format::$T, Case::Lower)
             }
         }
 
         impl<$($a),*> fmt::UpperHex for $T<$($a),*> {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                f.write_str(self.encode_upper(&mut [0; Self::LENGTH]))
+                self.0.encode(f, Format::$T, Case::Upper)
             }
         }
 
@@ -924,6 +1499,279 @@ impl_fmt_traits! {
 mod tests {
     use super::*;
 
+    #[test]
+    fn encode_every_format_and_case() {
+        use crate::std::string::{String, ToString};
+
+        let uuid = Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4").unwrap();
+
+        let cases = [
+            (
+                Format::Hyphenated,
+                Case::Lower,
+                "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4",
+            ),
+            (
+                Format::Hyphenated,
+                Case::Upper,
+                "F9168C5E-CEB2-4FAA-B6BF-329BF39FA1E4",
+            ),
+            (
+                Format::Simple,
+                Case::Lower,
+                "f9168c5eceb24faab6bf329bf39fa1e4",
+            ),
+            (
+                Format::Simple,
+                Case::Upper,
+                "F9168C5ECEB24FAAB6BF329BF39FA1E4",
+            ),
+            (
+                Format::Urn,
+                Case::Lower,
+                "urn:uuid:f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4",
+            ),
+            (
+                Format::Urn,
+                Case::Upper,
+                "urn:uuid:F9168C5E-CEB2-4FAA-B6BF-329BF39FA1E4",
+            ),
+            (
+                Format::Braced,
+                Case::Lower,
+                "{f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4}",
+            ),
+            (
+                Format::Braced,
+                Case::Upper,
+                "{F9168C5E-CEB2-4FAA-B6BF-329BF39FA1E4}",
+            ),
+        ];
+
+        for (format, case, expected) in cases.iter() {
+            let mut s = String::new();
+            uuid.encode(&mut s, *format, *case).unwrap();
+
+            assert_eq!(s, *expected);
+            assert_eq!(Uuid::parse_str(&s).unwrap(), uuid);
+        }
+
+        // The formatters produce the same output as the primitive
+        assert_eq!(uuid.hyphenated().to_string(), cases[0].2);
+        assert_eq!(format!("{:X}", uuid.simple()), cases[3].2);
+        assert_eq!(uuid.as_urn().to_string(), cases[4].2);
+        assert_eq!(format!("{:X}", uuid.as_braced()), cases[7].2);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn short() {
+        let uuid = Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4").unwrap();
+
+        assert_eq!(uuid.short(), "f9168c5e");
+        assert_eq!(Uuid::nil().short(), "00000000");
+        assert!(uuid.matches_short(&uuid.short()));
+    }
+
+    #[test]
+    fn prefix_matches() {
+        let uuid = Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4").unwrap();
+
+        let matching = [
+            "f",
+            "F9",
+            "f9168c5e",
+            "f9168c5e-",
+            "f9168c5ec",
+            "f9168c5e-c",
+            "f9168c5eceb24faa",
+            "f9168c5e-ceb2-4faa",
+            "f9168c5e-ceb24faa-b6bf",
+            "F9168C5ECEB24FAAB6BF329BF39FA1E4",
+            "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4",
+        ];
+
+        for prefix in matching.iter() {
+            assert!(uuid.prefix_matches(prefix), "{}", prefix);
+        }
+
+        let other = [
+            "",
+            "-",
+            "-f9168c5e",
+            "9168c5e",
+            "f9168c5f",
+            "f916-8c5e",
+            "f9168c5e--ceb2",
+            "f9168c5e-ceb2-4fab",
+            "f9168c5eceb24faab6bf329bf39fa1e45",
+            "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4-",
+            "{f9168c5e",
+            "f9168c5é",
+        ];
+
+        for prefix in other.iter() {
+            assert!(!uuid.prefix_matches(prefix), "{}", prefix);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn compact() {
+        let uuid = Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4").unwrap();
+
+        assert_eq!(uuid.compact(), "f9168c5e…a1e4");
+        assert_eq!(Uuid::nil().compact(), "00000000…0000");
+
+        let compact = uuid.compact();
+        assert_eq!(compact.chars().count(), 13);
+        assert_eq!(compact.len(), compact.capacity());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn oid_string() {
+        assert_eq!(Uuid::nil().to_oid_string(), "2.25.0");
+        assert_eq!(
+            Uuid::from_u128(u128::MAX).to_oid_string(),
+            "2.25.340282366920938463463374607431768211455"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn decimal_string() {
+        assert_eq!(
+            Uuid::nil().to_decimal_string(),
+            "000000000000000000000000000000000000000"
+        );
+        assert_eq!(
+            Uuid::from_u128(u128::MAX).to_decimal_string(),
+            "340282366920938463463374607431768211455"
+        );
+
+        let uuid = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+        assert_eq!(39, uuid.to_decimal_string().len());
+        assert!(Uuid::nil().to_decimal_string() < uuid.to_decimal_string());
+    }
+
+    #[test]
+    fn matches_short() {
+        let uuid = Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4").unwrap();
+
+        let matching = [
+            "f",
+            "f9168c5e",
+            "F9168C5E",
+            "f9168C5e",
+            "f9168c5e-",
+            "f9168c5e-ceb2",
+            "F9168C5E-CEB2-4FAA-B6BF-329BF39FA1E4",
+        ];
+
+        for short in matching.iter() {
+            assert!(uuid.matches_short(short), "{}", short);
+        }
+
+        let other = [
+            "",
+            "9168c5e",
+            "f9168c5f",
+            "f9168c5eceb2",
+            "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e45",
+            "{f9168c5e}",
+            "f9168c5é",
+        ];
+
+        for short in other.iter() {
+            assert!(!uuid.matches_short(short), "{}", short);
+        }
+    }
+
+    #[test]
+    fn format_matches_formatters() {
+        use crate::std::string::ToString;
+
+        let uuid = Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4").unwrap();
+
+        let cases = [
+            (
+                Format::Hyphenated,
+                uuid.hyphenated().to_string(),
+                format!("{:X}", uuid.hyphenated()),
+            ),
+            (
+                Format::Simple,
+                uuid.simple().to_string(),
+                format!("{:X}", uuid.simple()),
+            ),
+            (
+                Format::Urn,
+                uuid.urn().to_string(),
+                format!("{:X}", uuid.urn()),
+            ),
+            (
+                Format::Braced,
+                uuid.braced().to_string(),
+                format!("{:X}", uuid.braced()),
+            ),
+        ];
+
+        for (format, lower, upper) in cases.iter() {
+            let formatted = uuid.format(*format);
+
+            assert_eq!(formatted.to_string(), *lower);
+            assert_eq!(format!("{:x}", formatted), *lower);
+            assert_eq!(format!("{:X}", formatted), *upper);
+            assert_eq!(formatted.format(), *format);
+            assert_eq!(formatted.into_uuid(), uuid);
+        }
+    }
+
+    #[test]
+    fn upper_roundtrip() {
+        use crate::std::string::ToString;
+
+        let uuid = Uuid::parse_str("F9168c5E-ceB2-4FAA-b6bf-329bF39fa1e4").unwrap();
+
+        let mut buf = Uuid::encode_buffer();
+
+        let cases = [
+            (
+                format!("{:X}", uuid.hyphenated()),
+                uuid.hyphenated().encode_upper(&mut buf).to_string(),
+                "F9168C5E-CEB2-4FAA-B6BF-329BF39FA1E4",
+            ),
+            (
+                format!("{:X}", uuid.simple()),
+                uuid.simple().encode_upper(&mut buf).to_string(),
+                "F9168C5ECEB24FAAB6BF329BF39FA1E4",
+            ),
+            (
+                format!("{:X}", uuid.urn()),
+                uuid.urn().encode_upper(&mut buf).to_string(),
+                "urn:uuid:F9168C5E-CEB2-4FAA-B6BF-329BF39FA1E4",
+            ),
+            (
+                format!("{:X}", uuid.braced()),
+                uuid.braced().encode_upper(&mut buf).to_string(),
+                "{F9168C5E-CEB2-4FAA-B6BF-329BF39FA1E4}",
+            ),
+        ];
+
+        for (formatted, encoded, expected) in cases.iter() {
+            assert_eq!(formatted, expected);
+            assert_eq!(encoded, expected);
+            assert_eq!(Uuid::parse_str(expected), Ok(uuid));
+        }
+
+        // The lower-case output isn't affected by the case of the input
+        assert_eq!(
+            uuid.hyphenated().encode_lower(&mut buf),
+            "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4"
+        );
+    }
+
     #[test]
     fn hyphenated_trailing() {
         let mut buf = [b'x'; 100];
@@ -956,6 +1804,18 @@ mod tests {
         assert!(buf[len..].iter().all(|x| *x == b'x'));
     }
 
+    #[test]
+    fn encode_simple_lower() {
+        let uuid = Uuid::parse_str("F9168C5E-CEB2-4FAA-B6BF-329BF39FA1E4").unwrap();
+
+        let mut buf = [b'x'; 32];
+        assert_eq!(
+            uuid.encode_simple_lower(&mut buf),
+            uuid.simple().encode_lower(&mut Uuid::encode_buffer())
+        );
+        assert_eq!(&buf, b"f9168c5eceb24faab6bf329bf39fa1e4");
+    }
+
     #[test]
     fn urn_trailing() {
         let mut buf = [b'x'; 100];
@@ -989,29 +1849,79 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
+    fn braced_roundtrip() {
+        let uuid = Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4").unwrap();
+
+        let mut lower = [0; Braced::LENGTH];
+        let mut upper = [0; Braced::LENGTH];
+
+        for encoded in [
+            &*uuid.braced().encode_lower(&mut lower),
+            &*uuid.braced().encode_upper(&mut upper),
+        ] {
+            assert_eq!(
+                Ok((uuid, Format::Braced)),
+                Uuid::parse_str_detect(encoded),
+                "{}",
+                encoded
+            );
+            assert_eq!(
+                Ok(uuid),
+                Uuid::parse_with(encoded, crate::ParseMode::Braced)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "the buffer is too small to encode a UUID: expected at least 36 bytes, found 35"
+    )]
     fn hyphenated_too_small() {
         Uuid::nil().hyphenated().encode_lower(&mut [0; 35]);
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(
+        expected = "the buffer is too small to encode a UUID: expected at least 32 bytes, found 31"
+    )]
     fn simple_too_small() {
         Uuid::nil().simple().encode_lower(&mut [0; 31]);
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(
+        expected = "the buffer is too small to encode a UUID: expected at least 45 bytes, found 44"
+    )]
     fn urn_too_small() {
         Uuid::nil().urn().encode_lower(&mut [0; 44]);
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(
+        expected = "the buffer is too small to encode a UUID: expected at least 38 bytes, found 37"
+    )]
     fn braced_too_small() {
         Uuid::nil().braced().encode_lower(&mut [0; 37]);
     }
 
+    #[test]
+    fn hyphenated_upper_stack_buffer() {
+        let uuid = Uuid::parse_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4").unwrap();
+
+        let mut buf = [0; Hyphenated::LENGTH];
+        assert_eq!(
+            uuid.hyphenated().encode_upper(&mut buf),
+            "F9168C5E-CEB2-4FAA-B6BF-329BF39FA1E4"
+        );
+        assert_eq!(&buf, b"F9168C5E-CEB2-4FAA-B6BF-329BF39FA1E4");
+    }
+
+    #[test]
+    #[should_panic]
+    fn hyphenated_upper_too_small() {
+        Uuid::nil().hyphenated().encode_upper(&mut [0; 35]);
+    }
+
     #[test]
     fn hyphenated_to_inner() {
         let hyphenated = Uuid::nil().hyphenated();
@@ -105,9 +105,18 @@
 //!   `serde`.
 //! * `arbitrary` - adds an `Arbitrary` trait implementation to `Uuid` for
 //!   fuzzing.
+//! * `rand_core` - adds the ability to generate a random UUID using any
+//!   RNG that implements `rand_core::RngCore`, in no-std environments too.
 //! * `fast-rng` - uses a faster algorithm for generating random UUIDs.
 //!   This feature requires more dependencies to compile, but is just as suitable for
-//!   UUIDs as the default algorithm.
+//!   UUIDs as the default algorithm. Instead of asking the operating system for
+//!   random bytes for every UUID, it uses the thread-local generator from `rand`,
+//!   which is a ChaCha-based CSPRNG that's seeded from the operating system and
+//!   periodically reseeded from it. With the `v4` feature it also adds
+//!   `FastV4Generator`, a ChaCha20 generator that can be owned by a single
+//!   thread or task.
+//! * `phonetic` - adds the ability to encode a UUID as a sequence of words
+//!   that are easy to read aloud.
 //! * `rocket` - adds a trait implementation for [FromRequest](https://api.rocket.rs/v0.5-rc/rocket/request/trait.FromRequest.html) 
 //!   and [FromParam](https://api.rocket.rs/v0.5-rc/rocket/request/trait.FromParam.html) to `Uuid`. 
 //! 
@@ -163,6 +172,7 @@
 //!
 //! * `v1`, `v3`, `v5`, `v6`, and `v8`.
 //! * `serde`.
+//! * `rand_core`.
 //!
 //! If you need to use `v4` or `v7` in a no-std environment, you'll need to
 //! follow [`getrandom`'s docs] on configuring a source of randomness
@@ -236,9 +246,25 @@ pub mod timestamp;
 
 pub use timestamp::{context::NoContext, ClockSequence, Timestamp};
 
+#[cfg(uuid_unstable)]
+pub use timestamp::Clock;
+#[cfg(all(uuid_unstable, feature = "std"))]
+pub use timestamp::SystemClock;
+
 #[cfg(any(feature = "v1", feature = "v6"))]
 pub use timestamp::context::Context;
 
+#[cfg(feature = "v3")]
+pub use v3::V3Hasher;
+#[cfg(all(feature = "v4", feature = "fast-rng"))]
+pub use v4::FastV4Generator;
+#[cfg(all(feature = "v4", feature = "std"))]
+pub use v4::SelfTestError;
+#[cfg(feature = "v4")]
+pub use v4::UuidPool;
+#[cfg(feature = "v5")]
+pub use v5::V5Hasher;
+
 #[cfg(feature = "v1")]
 #[doc(hidden)]
 // Soft-deprecated (Rust doesn't support deprecating re-exports)
@@ -257,6 +283,9 @@ mod v7;
 #[cfg(all(uuid_unstable, feature = "v8"))]
 mod v8;
 
+#[cfg(feature = "phonetic")]
+mod phonetic;
+
 #[cfg(feature = "md5")]
 mod md5;
 #[cfg(feature = "rng")]
@@ -276,9 +305,13 @@ mod macros;
 #[cfg(feature = "macro-diagnostics")]
 pub extern crate uuid_macro_internal;
 
-use crate::std::convert;
+use crate::std::{cmp, convert, hash};
 
-pub use crate::{builder::Builder, error::Error};
+pub use crate::{
+    builder::Builder,
+    error::Error,
+    parser::{CaseInsensitiveUuidStr, ParseMode, HEX_DECODE_TABLE},
+};
 
 /// A 128-bit (16 byte) buffer containing the UUID.
 ///
@@ -341,6 +374,43 @@ pub enum Variant {
     Future,
 }
 
+/// The byte order of the integer fields in a UUID.
+///
+/// See the [Endianness](struct.Uuid.html#endianness) section on [`Uuid`]
+/// for details on how this applies to the fields of a UUID.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Endianness {
+    /// The fields are in big-endian order, as specified by RFC4122 (default).
+    Big,
+    /// The fields are in little-endian order, as used by Microsoft GUIDs.
+    Little,
+}
+
+/// Everything that can be read from a UUID without any other context.
+///
+/// This is returned by [`Uuid::describe`]. The [`fmt::Display`]
+/// implementation lists each field on its own line, which is intended for
+/// people rather than programs.
+///
+/// [`Uuid::describe`]: struct.Uuid.html#method.describe
+/// [`fmt::Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct UuidInfo {
+    /// The version of the UUID, if it's a known version.
+    pub version: Option<Version>,
+    /// The variant of the UUID.
+    pub variant: Variant,
+    /// Whether the UUID is the nil UUID.
+    pub is_nil: bool,
+    /// Whether the UUID is the max UUID.
+    pub is_max: bool,
+    /// The timestamp of the UUID, for versions that contain one.
+    pub timestamp: Option<Timestamp>,
+    /// The node ID of the UUID, for versions that contain one.
+    pub node_id: Option<[u8; 6]>,
+}
+
 /// A Universally Unique Identifier (UUID).
 ///
 /// # Examples
@@ -432,6 +502,17 @@ pub enum Variant {
 /// - Endianness roundtrips, so if you create a UUID with `from_fields_le`
 ///   you'll get the same values back out with `to_fields_le`.
 ///
+/// # Ordering
+///
+/// UUIDs are ordered by comparing their bytes lexicographically, the same as
+/// [`Uuid::cmp_bytes`]. This doesn't depend on the endianness of the target,
+/// so sorted UUIDs will be in the same order on every platform.
+///
+/// Version 6 and 7 UUIDs put the most significant bits of their timestamp
+/// first, so they sort by the time they were created. Version 1 UUIDs don't,
+/// because their field layout starts with the least significant bits of the
+/// timestamp.
+///
 /// # ABI
 ///
 /// The `Uuid` type is always guaranteed to be have the same ABI as [`Bytes`].
@@ -457,31 +538,79 @@ pub enum Variant {
 /// // As cookie
 /// let cookie = Cookie::new("uuid", uuid.to_string());
 /// ```
-#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(all(uuid_unstable, feature = "zerocopy"), derive(AsBytes, FromBytes, Unaligned))]
 #[repr(transparent)]
 pub struct Uuid(Bytes);
 
 impl Uuid {
     /// UUID namespace for Domain Name System (DNS).
+    ///
+    /// This is `6ba7b810-9dad-11d1-80b4-00c04fd430c8`, as defined in RFC4122 Appendix C.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// assert_eq!(
+    ///     "6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+    ///     Uuid::NAMESPACE_DNS.hyphenated().to_string(),
+    /// );
+    /// ```
     pub const NAMESPACE_DNS: Self = Uuid([
         0x6b, 0xa7, 0xb8, 0x10, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30,
         0xc8,
     ]);
 
     /// UUID namespace for ISO Object Identifiers (OIDs).
+    ///
+    /// This is `6ba7b812-9dad-11d1-80b4-00c04fd430c8`, as defined in RFC4122 Appendix C.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// assert_eq!(
+    ///     "6ba7b812-9dad-11d1-80b4-00c04fd430c8",
+    ///     Uuid::NAMESPACE_OID.hyphenated().to_string(),
+    /// );
+    /// ```
     pub const NAMESPACE_OID: Self = Uuid([
         0x6b, 0xa7, 0xb8, 0x12, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30,
         0xc8,
     ]);
 
     /// UUID namespace for Uniform Resource Locators (URLs).
+    ///
+    /// This is `6ba7b811-9dad-11d1-80b4-00c04fd430c8`, as defined in RFC4122 Appendix C.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// assert_eq!(
+    ///     "6ba7b811-9dad-11d1-80b4-00c04fd430c8",
+    ///     Uuid::NAMESPACE_URL.hyphenated().to_string(),
+    /// );
+    /// ```
     pub const NAMESPACE_URL: Self = Uuid([
         0x6b, 0xa7, 0xb8, 0x11, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30,
         0xc8,
     ]);
 
     /// UUID namespace for X.500 Distinguished Names (DNs).
+    ///
+    /// This is `6ba7b814-9dad-11d1-80b4-00c04fd430c8`, as defined in RFC4122 Appendix C.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// assert_eq!(
+    ///     "6ba7b814-9dad-11d1-80b4-00c04fd430c8",
+    ///     Uuid::NAMESPACE_X500.hyphenated().to_string(),
+    /// );
+    /// ```
     pub const NAMESPACE_X500: Self = Uuid([
         0x6b, 0xa7, 0xb8, 0x14, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30,
         0xc8,
@@ -638,6 +767,10 @@ impl Uuid {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// Each field can also be read on its own, and in const contexts, using
+    /// [`Uuid::time_low`], [`Uuid::time_mid`], [`Uuid::time_hi_and_version`],
+    /// [`Uuid::clock_seq`], and [`Uuid::node`].
     pub fn as_fields(&self) -> (u32, u16, u16, &[u8; 8]) {
         let bytes = self.as_bytes();
 
@@ -661,6 +794,10 @@ impl Uuid {
     /// rather than the target environment so bytes will be flipped on both
     /// big and little endian machines.
     ///
+    /// This is the inverse of [`Uuid::from_fields_le`]. It's useful for
+    /// filling in a GUID structure that stores its fields separately, like
+    /// the one used on Windows.
+    ///
     /// # Examples
     ///
     /// ```
@@ -669,6 +806,9 @@ impl Uuid {
     /// # fn main() -> Result<(), uuid::Error> {
     /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
     ///
+    /// let (d1, d2, d3, d4) = uuid.to_fields_le();
+    /// assert_eq!(uuid, Uuid::from_fields_le(d1, d2, d3, d4));
+    ///
     /// assert_eq!(
     ///     uuid.to_fields_le(),
     ///     (
@@ -681,6 +821,8 @@ impl Uuid {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// [`Uuid::from_fields_le`]: #method.from_fields_le
     pub fn to_fields_le(&self) -> (u32, u16, u16, &[u8; 8]) {
         let d1 = (self.as_bytes()[0] as u32)
             | (self.as_bytes()[1] as u32) << 8
@@ -695,9 +837,235 @@ impl Uuid {
         (d1, d2, d3, d4)
     }
 
+    /// Returns the `time_low` field of the UUID.
+    ///
+    /// This is the first group of (eight) hex digits, taken as a big-endian
+    /// `u32` value. It's the same as the first value returned by
+    /// [`Uuid::as_fields`]. The field is named after its meaning in version 1
+    /// UUIDs, where it holds the low 32 bits of the timestamp, but it can be
+    /// read from a UUID of any version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    ///
+    /// assert_eq!(uuid.time_low(), 0xa1a2a3a4);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn time_low(&self) -> u32 {
+        let bytes = self.as_bytes();
+
+        (bytes[0] as u32) << 24 | (bytes[1] as u32) << 16 | (bytes[2] as u32) << 8 | bytes[3] as u32
+    }
+
+    /// Returns the `time_mid` field of the UUID.
+    ///
+    /// This is the second group of (four) hex digits, taken as a big-endian
+    /// `u16` value. For version 1 UUIDs it holds the middle 16 bits of the
+    /// timestamp.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    ///
+    /// assert_eq!(uuid.time_mid(), 0xb1b2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn time_mid(&self) -> u16 {
+        (self.as_bytes()[4] as u16) << 8 | self.as_bytes()[5] as u16
+    }
+
+    /// Returns the `time_hi_and_version` field of the UUID.
+    ///
+    /// This is the third group of (four) hex digits, taken as a big-endian
+    /// `u16` value. The 4 most significant bits are the version, and for
+    /// version 1 UUIDs the other 12 bits are the high bits of the timestamp.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    ///
+    /// assert_eq!(uuid.time_hi_and_version(), 0xc1c2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn time_hi_and_version(&self) -> u16 {
+        (self.as_bytes()[6] as u16) << 8 | self.as_bytes()[7] as u16
+    }
+
+    /// Returns the clock sequence field of the UUID.
+    ///
+    /// This is the fourth group of (four) hex digits, taken as a big-endian
+    /// `u16` value. The value is returned as-is, so it includes the variant
+    /// in its most significant bits. For RFC4122 UUIDs, masking it with
+    /// `0x3fff` gives the 14 bit clock sequence of version 1 UUIDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    ///
+    /// assert_eq!(uuid.clock_seq(), 0xd1d2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn clock_seq(&self) -> u16 {
+        (self.as_bytes()[8] as u16) << 8 | self.as_bytes()[9] as u16
+    }
+
+    /// Returns the node field of the UUID.
+    ///
+    /// This is the last group of (twelve) hex digits. For version 1 UUIDs it
+    /// holds the node ID, which is often a MAC address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    ///
+    /// assert_eq!(uuid.node(), [0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn node(&self) -> [u8; 6] {
+        let bytes = self.as_bytes();
+
+        [
+            bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15],
+        ]
+    }
+
+    /// Returns a copy of the UUID with its node field replaced.
+    ///
+    /// This overwrites the last 6 bytes of the UUID, which hold the node ID
+    /// of version 1 and 6 UUIDs, and leaves everything else, including the
+    /// timestamp, version, and variant, untouched. It can be used to
+    /// anonymize the MAC address in an existing UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("6ba7b810-9dad-11d1-80b4-00c04fd430c8")?;
+    ///
+    /// let uuid = uuid.with_node_id([0x01, 0x02, 0x03, 0x04, 0x05, 0x06]);
+    ///
+    /// assert_eq!("6ba7b810-9dad-11d1-80b4-010203040506", uuid.to_string());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn with_node_id(self, node_id: [u8; 6]) -> Uuid {
+        let mut bytes = self.0;
+
+        let mut i = 0;
+        while i < 6 {
+            bytes[10 + i] = node_id[i];
+            i += 1;
+        }
+
+        Uuid(bytes)
+    }
+
+    /// Returns a copy of the UUID with its clock sequence replaced.
+    ///
+    /// The low 14 bits of `clock_seq` are written to the clock sequence
+    /// field used by version 1 and 6 UUIDs. The 2 most significant bits of
+    /// the field are kept as they are, so an RFC4122 variant is preserved,
+    /// and the high bits of `clock_seq` are ignored. Everything else,
+    /// including the timestamp and version, is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("6ba7b810-9dad-11d1-80b4-00c04fd430c8")?;
+    ///
+    /// let uuid = uuid.with_clock_seq(0x1234);
+    ///
+    /// assert_eq!("6ba7b810-9dad-11d1-9234-00c04fd430c8", uuid.to_string());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn with_clock_seq(self, clock_seq: u16) -> Uuid {
+        let mut bytes = self.0;
+
+        bytes[8] = (bytes[8] & 0xc0) | ((clock_seq >> 8) as u8 & 0x3f);
+        bytes[9] = clock_seq as u8;
+
+        Uuid(bytes)
+    }
+
+    /// Returns a copy of the UUID with its lowest `bits` bits set to zero.
+    ///
+    /// This deliberately destroys the uniqueness of the UUID: every UUID that
+    /// only differs in those bits is mapped to the same value, so each result
+    /// stands for a bucket of 2<sup>`bits`</sup> UUIDs. That can be used to
+    /// publish coarsened identifiers where individual values shouldn't be
+    /// distinguishable. The `bits` are counted from the end of the UUID when
+    /// it's treated as a big-endian `u128`, so they may include the version
+    /// and variant if `bits` is large enough. Clearing all 128 bits returns
+    /// the nil UUID.
+    ///
+    /// The result shouldn't be used as an identifier for anything.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `bits` is more than 128.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// assert_eq!(
+    ///     "67e55044-10b1-426f-9247-bb6800000000",
+    ///     uuid.truncate_low_bits(32).to_string(),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn truncate_low_bits(self, bits: u32) -> Uuid {
+        assert!(bits <= 128, "can't clear more than 128 bits of a UUID");
+
+        // Shifting a `u128` by 128 bits overflows, so clearing every bit is
+        // handled separately
+        let mask = if bits == 128 { 0 } else { u128::MAX << bits };
+
+        Uuid::from_u128(self.as_u128() & mask)
+    }
+
     /// Returns a 128bit value containing the value.
     ///
-    /// The bytes in the UUID will be packed directly into a `u128`.
+    /// The bytes in the UUID will be packed directly into a `u128`, so the
+    /// value is big-endian: the first byte of the UUID is the most
+    /// significant byte of the `u128`. This is the same order as the string
+    /// forms of the UUID, and the same value as [`Uuid::to_be_u128`]. Use
+    /// [`Uuid::to_u128_le`] for the little-endian value instead.
+    ///
+    /// This method is the exact inverse of [`Uuid::from_u128`], so
+    /// `Uuid::from_u128(uuid.as_u128())` is always equal to `uuid`, and
+    /// `Uuid::from_u128(v).as_u128()` is always equal to `v`. Both methods
+    /// are `const`, so they can be used to build lookup tables at compile
+    /// time.
     ///
     /// # Examples
     ///
@@ -713,6 +1081,30 @@ impl Uuid {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// Round-tripping through the numeric form, such as when it's used
+    /// as a sharding key:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let v = 0xa1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8;
+    ///
+    /// assert_eq!(v, Uuid::from_u128(v).as_u128());
+    /// ```
+    ///
+    /// Using the value in a const context:
+    ///
+    /// ```
+    /// # use uuid::{uuid, Uuid};
+    /// const IDS: [u128; 2] = [
+    ///     uuid!("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8").as_u128(),
+    ///     Uuid::NAMESPACE_DNS.as_u128(),
+    /// ];
+    ///
+    /// assert_eq!(Uuid::NAMESPACE_DNS, Uuid::from_u128(IDS[1]));
+    /// ```
+    ///
+    /// [`Uuid::from_u128`]: #method.from_u128
     pub const fn as_u128(&self) -> u128 {
         (self.as_bytes()[0] as u128) << 120
             | (self.as_bytes()[1] as u128) << 112
@@ -734,8 +1126,9 @@ impl Uuid {
 
     /// Returns a 128bit little-endian value containing the value.
     ///
-    /// The bytes in the `u128` will be flipped to convert into big-endian
-    /// order. This is based on the endianness of the UUID, rather than the
+    /// The bytes in the `u128` will be flipped to convert into little-endian
+    /// order, so this is `uuid.as_u128().swap_bytes()`, and the inverse of
+    /// [`Uuid::from_u128_le`]. This is based on the endianness of the UUID, rather than the
     /// target environment so bytes will be flipped on both big and little
     /// endian machines.
     ///
@@ -754,6 +1147,8 @@ impl Uuid {
     ///     uuid.to_u128_le(),
     ///     0xd8d7d6d5d4d3d2d1c2c1b2b1a4a3a2a1,
     /// );
+    ///
+    /// assert_eq!(uuid, Uuid::from_u128_le(uuid.to_u128_le()));
     /// # Ok(())
     /// # }
     /// ```
@@ -776,11 +1171,11 @@ impl Uuid {
             | (self.as_bytes()[15] as u128) << 120
     }
 
-    /// Returns two 64bit values containing the value.
+    /// Returns a 128bit big-endian value containing the value.
     ///
-    /// The bytes in the UUID will be split into two `u64`.
-    /// The first u64 represents the 64 most significant bits,
-    /// the second one represents the 64 least significant.
+    /// This is the same as [`Uuid::as_u128`], with a name that says which byte
+    /// order is used, like [`u128::to_be_bytes`]. It's the inverse of
+    /// [`Uuid::from_u128`].
     ///
     /// # Examples
     ///
@@ -788,65 +1183,380 @@ impl Uuid {
     /// # use uuid::Uuid;
     /// # fn main() -> Result<(), uuid::Error> {
     /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    ///
     /// assert_eq!(
-    ///     uuid.as_u64_pair(),
-    ///     (0xa1a2a3a4b1b2c1c2, 0xd1d2d3d4d5d6d7d8),
+    ///     uuid.to_be_u128(),
+    ///     0xa1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8,
     /// );
+    /// assert_eq!(uuid.as_u128(), uuid.to_be_u128());
     /// # Ok(())
     /// # }
     /// ```
-    pub const fn as_u64_pair(&self) -> (u64, u64) {
-        let value = self.as_u128();
-        ((value >> 64) as u64, value as u64)
+    pub const fn to_be_u128(&self) -> u128 {
+        self.as_u128()
     }
 
-    /// Returns a slice of 16 octets containing the value.
+    /// Returns a 128bit little-endian value containing the value.
     ///
-    /// This method borrows the underlying byte value of the UUID.
+    /// This is the same as [`Uuid::to_u128_le`], with a name that says which
+    /// byte order is used, like [`u128::to_le_bytes`]. It's the inverse of
+    /// [`Uuid::from_u128_le`].
     ///
     /// # Examples
     ///
     /// ```
     /// # use uuid::Uuid;
-    /// let bytes1 = [
-    ///     0xa1, 0xa2, 0xa3, 0xa4,
-    ///     0xb1, 0xb2,
-    ///     0xc1, 0xc2,
-    ///     0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8,
-    /// ];
-    /// let uuid1 = Uuid::from_bytes_ref(&bytes1);
-    ///
-    /// let bytes2 = uuid1.as_bytes();
-    /// let uuid2 = Uuid::from_bytes_ref(bytes2);
-    ///
-    /// assert_eq!(uuid1, uuid2);
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
     ///
-    /// assert!(std::ptr::eq(
-    ///     uuid2 as *const Uuid as *const u8,
-    ///     &bytes1 as *const [u8; 16] as *const u8,
-    /// ));
+    /// assert_eq!(
+    ///     uuid.to_le_u128(),
+    ///     0xd8d7d6d5d4d3d2d1c2c1b2b1a4a3a2a1,
+    /// );
+    /// assert_eq!(uuid, Uuid::from_u128_le(uuid.to_le_u128()));
+    /// # Ok(())
+    /// # }
     /// ```
-    pub const fn as_bytes(&self) -> &Bytes {
-        &self.0
+    pub const fn to_le_u128(&self) -> u128 {
+        self.to_u128_le()
     }
 
-    /// Consumes self and returns the underlying byte value of the UUID.
+    /// Returns a 128bit value in the native byte order of the target
+    /// containing the value.
+    ///
+    /// The bytes of the returned `u128` are laid out in memory exactly like
+    /// the bytes of the UUID, so it can be stored in a `u128` field of a
+    /// `#[repr(C)]` struct, or passed through a C `__int128`, and turned back
+    /// into the same UUID with [`Uuid::from_u128_ne`]. On big-endian targets
+    /// this is the same as [`Uuid::as_u128`], and on little-endian targets
+    /// it's the same as [`Uuid::to_u128_le`].
+    ///
+    /// The number itself depends on the target, so it shouldn't be compared,
+    /// sorted, or serialized. Use [`Uuid::as_u128`] for that instead.
     ///
     /// # Examples
     ///
     /// ```
     /// # use uuid::Uuid;
-    /// let bytes = [
-    ///     0xa1, 0xa2, 0xa3, 0xa4,
-    ///     0xb1, 0xb2,
-    ///     0xc1, 0xc2,
-    ///     0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8,
-    /// ];
-    /// let uuid = Uuid::from_bytes(bytes);
-    /// assert_eq!(bytes, uuid.into_bytes());
-    /// ```
-    pub const fn into_bytes(self) -> Bytes {
-        self.0
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    ///
+    /// assert_eq!(&uuid.to_u128_ne().to_ne_bytes(), uuid.as_bytes());
+    /// assert_eq!(Uuid::from_u128_ne(uuid.to_u128_ne()), uuid);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Uuid::from_u128_ne`]: #method.from_u128_ne
+    pub const fn to_u128_ne(&self) -> u128 {
+        u128::from_ne_bytes(self.0)
+    }
+
+    /// Splits the 128bit value of the UUID into two at a bit offset.
+    ///
+    /// The first value contains the most significant `bit` bits of the UUID,
+    /// and the second contains the remaining `128 - bit` bits. Both values
+    /// are right-aligned, so splitting at `0` returns `(0, uuid.as_u128())`
+    /// and splitting at `128` returns `(uuid.as_u128(), 0)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit` is greater than 128.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    ///
+    /// assert_eq!(
+    ///     uuid.split_at_bit(12),
+    ///     (0xa1a, 0x2a3a4b1b2c1c2d1d2d3d4d5d6d7d8),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn split_at_bit(&self, bit: u32) -> (u128, u128) {
+        assert!(bit <= 128, "bit offset out of range");
+
+        let value = self.as_u128();
+
+        match bit {
+            0 => (0, value),
+            128 => (value, 0),
+            bit => (value >> (128 - bit), value & (u128::MAX >> bit)),
+        }
+    }
+
+    /// Returns two 64bit values containing the value.
+    ///
+    /// The bytes in the UUID will be split into two `u64`.
+    /// The first u64 represents the 64 most significant bits,
+    /// the second one represents the 64 least significant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    /// assert_eq!(
+    ///     uuid.as_u64_pair(),
+    ///     (0xa1a2a3a4b1b2c1c2, 0xd1d2d3d4d5d6d7d8),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn as_u64_pair(&self) -> (u64, u64) {
+        let value = self.as_u128();
+        ((value >> 64) as u64, value as u64)
+    }
+
+    /// Folds the UUID into a 64bit value by XORing its two halves together.
+    ///
+    /// This is the same as `high ^ low` using the values returned by
+    /// [`Uuid::as_u64_pair`]. It's a cheap, deterministic way to bucket UUIDs
+    /// by a 64bit key without a hasher. For random UUIDs the result is
+    /// evenly distributed, but it's neither collision-free nor cryptographic:
+    /// distinct UUIDs can fold to the same value, and it's trivial to
+    /// construct ones that do.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    ///
+    /// assert_eq!(
+    ///     uuid.fold_u64(),
+    ///     0xa1a2a3a4b1b2c1c2 ^ 0xd1d2d3d4d5d6d7d8,
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn fold_u64(&self) -> u64 {
+        let (high, low) = self.as_u64_pair();
+        high ^ low
+    }
+
+    /// Returns the most significant 64 bits of the UUID.
+    ///
+    /// This is the first value returned by [`Uuid::as_u64_pair`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    ///
+    /// assert_eq!(uuid.high_u64(), 0xa1a2a3a4b1b2c1c2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn high_u64(&self) -> u64 {
+        (self.as_u128() >> 64) as u64
+    }
+
+    /// Returns the least significant 64 bits of the UUID.
+    ///
+    /// This is the second value returned by [`Uuid::as_u64_pair`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
+    ///
+    /// assert_eq!(uuid.low_u64(), 0xd1d2d3d4d5d6d7d8);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn low_u64(&self) -> u64 {
+        self.as_u128() as u64
+    }
+
+    /// Whether this UUID and `other` look like they were allocated from the
+    /// same counter.
+    ///
+    /// This returns `true` if both UUIDs have the same [`Uuid::high_u64`],
+    /// and their [`Uuid::low_u64`] values are distinct but no more than
+    /// 65,536 apart. That's what a pair of IDs from an allocator that packs
+    /// a counter into the low bits would usually look like.
+    ///
+    /// This is only a heuristic. Two random UUIDs are astronomically unlikely
+    /// to pass it, but a counter-based allocator that also changes its high
+    /// bits, or that steps by large amounts, won't be detected. It's useful
+    /// for checks like spotting a batch of IDs that weren't randomly
+    /// generated, but shouldn't be relied on to distinguish them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::from_u64_pair(0xa1a2a3a4b1b2c1c2, 1);
+    ///
+    /// assert!(uuid.looks_sequential_with(&Uuid::from_u64_pair(0xa1a2a3a4b1b2c1c2, 2)));
+    /// assert!(!uuid.looks_sequential_with(&Uuid::from_u64_pair(0xa1a2a3a4b1b2c1c3, 2)));
+    /// ```
+    pub const fn looks_sequential_with(&self, other: &Uuid) -> bool {
+        const MAX_DISTANCE: u64 = 1 << 16;
+
+        if self.high_u64() != other.high_u64() {
+            return false;
+        }
+
+        let (a, b) = (self.low_u64(), other.low_u64());
+        // `u64::abs_diff` needs a newer compiler than our MSRV
+        #[allow(clippy::manual_abs_diff)]
+        let distance = if a > b { a - b } else { b - a };
+
+        distance != 0 && distance <= MAX_DISTANCE
+    }
+
+    /// Returns a slice of 16 octets containing the value.
+    ///
+    /// This method borrows the underlying byte value of the UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let bytes1 = [
+    ///     0xa1, 0xa2, 0xa3, 0xa4,
+    ///     0xb1, 0xb2,
+    ///     0xc1, 0xc2,
+    ///     0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8,
+    /// ];
+    /// let uuid1 = Uuid::from_bytes_ref(&bytes1);
+    ///
+    /// let bytes2 = uuid1.as_bytes();
+    /// let uuid2 = Uuid::from_bytes_ref(bytes2);
+    ///
+    /// assert_eq!(uuid1, uuid2);
+    ///
+    /// assert!(std::ptr::eq(
+    ///     uuid2 as *const Uuid as *const u8,
+    ///     &bytes1 as *const [u8; 16] as *const u8,
+    /// ));
+    /// ```
+    pub const fn as_bytes(&self) -> &Bytes {
+        &self.0
+    }
+
+    /// Returns a slice of 16 octets containing the value.
+    ///
+    /// This is the same as [`Uuid::as_bytes`], but returns a `&[u8]` instead
+    /// of a `&[u8; 16]`, which is convenient for code that's generic over
+    /// types with an `as_slice` method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// fn checksum(bytes: &[u8]) -> u8 {
+    ///     bytes.iter().fold(0, |acc, b| acc ^ b)
+    /// }
+    ///
+    /// let uuid = Uuid::from_u128(0xff);
+    ///
+    /// assert_eq!(16, uuid.as_slice().len());
+    /// assert_eq!(0xff, checksum(uuid.as_slice()));
+    /// ```
+    pub const fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Consumes self and returns the underlying byte value of the UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let bytes = [
+    ///     0xa1, 0xa2, 0xa3, 0xa4,
+    ///     0xb1, 0xb2,
+    ///     0xc1, 0xc2,
+    ///     0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8,
+    /// ];
+    /// let uuid = Uuid::from_bytes(bytes);
+    /// assert_eq!(bytes, uuid.into_bytes());
+    /// ```
+    pub const fn into_bytes(self) -> Bytes {
+        self.0
+    }
+
+    /// Compares the bytes of two UUIDs lexicographically.
+    ///
+    /// The bytes are compared one at a time, starting from the first, so
+    /// the result is the same on every target regardless of its endianness.
+    /// This is the same ordering used by the [`Ord`] implementation of
+    /// `Uuid`, and matches the ordering of the hyphenated and simple string
+    /// forms of a UUID using lower-case hex digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # use std::cmp::Ordering;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid1 = Uuid::parse_str("00ff0000-0000-0000-0000-000000000000")?;
+    /// let uuid2 = Uuid::parse_str("01000000-0000-0000-0000-000000000000")?;
+    ///
+    /// assert_eq!(Ordering::Less, uuid1.cmp_bytes(&uuid2));
+    /// assert_eq!(uuid1.cmp(&uuid2), uuid1.cmp_bytes(&uuid2));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn cmp_bytes(&self, other: &Uuid) -> cmp::Ordering {
+        let mut i = 0;
+        while i < 16 {
+            if self.0[i] < other.0[i] {
+                return cmp::Ordering::Less;
+            } else if self.0[i] > other.0[i] {
+                return cmp::Ordering::Greater;
+            }
+            i += 1;
+        }
+        cmp::Ordering::Equal
+    }
+
+    /// Compares two UUIDs the same way as Java's `UUID.compareTo`.
+    ///
+    /// Java compares the most significant 64 bits of each UUID first, then
+    /// the least significant 64 bits, but treats each half as a *signed*
+    /// `long`. Any half with its top bit set sorts before the halves
+    /// without it, so this ordering differs from the [`Ord`] implementation
+    /// of `Uuid`, which compares the bytes as unsigned values. Use this
+    /// method to match the order of data that was sorted by a JVM service,
+    /// or by a database that stores UUIDs as a pair of signed integers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # use std::cmp::Ordering;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid1 = Uuid::parse_str("7fffffff-ffff-ffff-ffff-ffffffffffff")?;
+    /// let uuid2 = Uuid::parse_str("80000000-0000-0000-0000-000000000000")?;
+    ///
+    /// assert_eq!(Ordering::Less, uuid1.cmp(&uuid2));
+    /// assert_eq!(Ordering::Greater, uuid1.java_cmp(&uuid2));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [`UUID.compareTo` in the Java SE documentation](https://docs.oracle.com/en/java/javase/17/docs/api/java.base/java/util/UUID.html#compareTo(java.util.UUID))
+    pub fn java_cmp(&self, other: &Uuid) -> cmp::Ordering {
+        let a = (self.high_u64() as i64, self.low_u64() as i64);
+        let b = (other.high_u64() as i64, other.low_u64() as i64);
+
+        a.cmp(&b)
     }
 
     /// Returns the bytes of the UUID in little-endian order.
@@ -881,61 +1591,334 @@ impl Uuid {
         ]
     }
 
-    /// Tests if the UUID is nil (all zeros).
-    pub const fn is_nil(&self) -> bool {
-        self.as_u128() == u128::MIN
-    }
-
-    /// Tests if the UUID is max (all ones).
-    #[cfg(uuid_unstable)]
-    pub const fn is_max(&self) -> bool {
-        self.as_u128() == u128::MAX
-    }
-
-    /// A buffer that can be used for `encode_...` calls, that is
-    /// guaranteed to be long enough for any of the format adapters.
+    /// Returns the bytes of the UUID in the native byte order of the target.
+    ///
+    /// The bytes are the same as those of [`Uuid::as_u128`] stored in memory
+    /// on the current target, so they can be used to interoperate with a
+    /// `u128` field in a locally defined `#[repr(C)]` struct. On big-endian
+    /// targets this is the same as [`Uuid::as_bytes`], on little-endian
+    /// targets the whole value is reversed.
+    ///
+    /// The result depends on the target, so it shouldn't be used for
+    /// serialization. Use [`Uuid::as_bytes`] for that instead.
     ///
     /// # Examples
     ///
     /// ```
     /// # use uuid::Uuid;
-    /// let uuid = Uuid::nil();
-    ///
-    /// assert_eq!(
-    ///     uuid.simple().encode_lower(&mut Uuid::encode_buffer()),
-    ///     "00000000000000000000000000000000"
-    /// );
-    ///
-    /// assert_eq!(
-    ///     uuid.hyphenated()
-    ///         .encode_lower(&mut Uuid::encode_buffer()),
-    ///     "00000000-0000-0000-0000-000000000000"
-    /// );
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
     ///
     /// assert_eq!(
-    ///     uuid.urn().encode_lower(&mut Uuid::encode_buffer()),
-    ///     "urn:uuid:00000000-0000-0000-0000-000000000000"
+    ///     uuid.to_ne_bytes(),
+    ///     uuid.as_u128().to_ne_bytes(),
     /// );
+    /// # Ok(())
+    /// # }
     /// ```
-    pub const fn encode_buffer() -> [u8; fmt::Urn::LENGTH] {
-        [0; fmt::Urn::LENGTH]
+    pub const fn to_ne_bytes(&self) -> Bytes {
+        self.as_u128().to_ne_bytes()
     }
 
-    /// If the UUID is the correct version (v1, v6, or v7) this will return
-    /// the timestamp and counter portion parsed from a V1 UUID.
+    /// Returns the UUID as four 32bit words in the native byte order of the
+    /// target.
     ///
-    /// Returns `None` if the supplied UUID is not V1.
+    /// Each word holds 4 consecutive bytes of the UUID, starting with the
+    /// first, so the array has the same layout in memory as
+    /// [`Uuid::as_bytes`]. The values of the words depend on the target.
+    /// See [`Uuid::from_u32_array_ne`] for details.
     ///
-    /// The V1 timestamp format defined in RFC4122 specifies a 60-bit
-    /// integer representing the number of 100-nanosecond intervals
-    /// since 00:00:00.00, 15 Oct 1582.
+    /// # Examples
     ///
-    /// [`Timestamp`] offers several options for converting the raw RFC4122
-    /// value into more commonly-used formats, such as a unix timestamp.
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8")?;
     ///
-    /// # Roundtripping
+    /// let words = uuid.as_u32_array_ne();
     ///
-    /// This method is unlikely to roundtrip a timestamp in a UUID due to the way
+    /// assert_eq!(words[0], u32::from_ne_bytes([0xa1, 0xa2, 0xa3, 0xa4]));
+    /// assert_eq!(Uuid::from_u32_array_ne(words), uuid);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Uuid::from_u32_array_ne`]: #method.from_u32_array_ne
+    pub const fn as_u32_array_ne(&self) -> [u32; 4] {
+        let b = self.as_bytes();
+
+        [
+            u32::from_ne_bytes([b[0], b[1], b[2], b[3]]),
+            u32::from_ne_bytes([b[4], b[5], b[6], b[7]]),
+            u32::from_ne_bytes([b[8], b[9], b[10], b[11]]),
+            u32::from_ne_bytes([b[12], b[13], b[14], b[15]]),
+        ]
+    }
+
+    /// Returns the bytes of the UUID in the mixed-endian layout used by GPT
+    /// partition tables.
+    ///
+    /// The UEFI specification stores GUIDs on disk with the first three
+    /// fields in little-endian order and the remaining eight bytes as-is:
+    ///
+    /// * bytes `0..4` are the first group of (eight) hex digits, reversed.
+    /// * bytes `4..6` are the second group of (four) hex digits, reversed.
+    /// * bytes `6..8` are the third group of (four) hex digits, reversed.
+    /// * bytes `8..16` are the last two groups of hex digits, in order.
+    ///
+    /// This is the same layout used by Windows GUIDs, and is equivalent to
+    /// [`Uuid::to_bytes_le`].
+    ///
+    /// # Examples
+    ///
+    /// The EFI System Partition type GUID as it appears on disk:
+    ///
+    /// ```
+    /// use uuid::Uuid;
+    ///
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("c12a7328-f81f-11d2-ba4b-00a0c93ec93b")?;
+    ///
+    /// assert_eq!(
+    ///     uuid.to_gpt_bytes(),
+    ///     [
+    ///         0x28, 0x73, 0x2a, 0xc1, 0x1f, 0xf8, 0xd2, 0x11, 0xba, 0x4b,
+    ///         0x00, 0xa0, 0xc9, 0x3e, 0xc9, 0x3b,
+    ///     ]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [GUID Partition Table in the UEFI Specification](https://uefi.org/specs/UEFI/2.10/05_GUID_Partition_Table_Format.html)
+    pub const fn to_gpt_bytes(&self) -> Bytes {
+        self.to_bytes_le()
+    }
+
+    /// Returns a check character for the UUID that catches typos when it's
+    /// read aloud or typed in by hand.
+    ///
+    /// The check character is computed with the Luhn mod N algorithm over the
+    /// 32 hex digits of the UUID, where N is 32:
+    ///
+    /// 1. Starting from the rightmost digit, the value of every other digit
+    ///    is doubled. Since a doubled hex digit is always less than 32, it is
+    ///    used as-is.
+    /// 2. The values are summed, and the check value is the amount that must
+    ///    be added to the sum to make it a multiple of 32.
+    /// 3. The check value is mapped to a character using the Crockford Base32
+    ///    alphabet: `0123456789ABCDEFGHJKMNPQRSTVWXYZ`.
+    ///
+    /// This detects any single hex digit that's changed and any two adjacent
+    /// hex digits that are swapped. The check character doesn't depend on
+    /// the case of the hex digits or the format the UUID is written in.
+    ///
+    /// Also see [`Uuid::from_checked_str`] to validate and strip a check
+    /// character.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// assert_eq!('A', uuid.check_char());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [Luhn mod N algorithm](https://en.wikipedia.org/wiki/Luhn_mod_N_algorithm)
+    /// * [Crockford's Base32](https://www.crockford.com/base32.html)
+    ///
+    /// [`Uuid::from_checked_str`]: #method.from_checked_str
+    pub const fn check_char(&self) -> char {
+        const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+        let bytes = self.as_bytes();
+
+        let mut sum = 0;
+        let mut i = 0;
+        while i < 16 {
+            // The low nibble of each byte is an odd number of digits from the right
+            sum += (bytes[i] >> 4) as usize + (bytes[i] & 0xf) as usize * 2;
+            i += 1;
+        }
+
+        ALPHABET[(32 - sum % 32) % 32] as char
+    }
+
+    /// Calculates a 16bit checksum of the UUID.
+    ///
+    /// The checksum is the CRC-16/CCITT-FALSE of the 16 bytes of the UUID:
+    /// the polynomial is `0x1021`, the initial value is `0xffff`, the bits
+    /// aren't reflected, and the result isn't XORed with anything. It can be
+    /// stored or displayed next to an abbreviated UUID to catch typos where
+    /// a longer checksum would take up too much space.
+    ///
+    /// The checksum is only meant to spot accidental changes. It's not
+    /// cryptographic, and with 65,536 possible values many UUIDs will share
+    /// the same checksum.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// assert_eq!(0xc95f, uuid.crc16());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [CRC-16/CCITT-FALSE in the catalogue of parametrised CRC algorithms](https://reveng.sourceforge.io/crc-catalogue/16.htm#crc.cat.crc-16-ibm-3740)
+    pub const fn crc16(&self) -> u16 {
+        const POLY: u16 = 0x1021;
+
+        // The CRC of each possible value of the high byte of the register
+        const TABLE: [u16; 256] = {
+            let mut table = [0; 256];
+            let mut i = 0;
+
+            while i < 256 {
+                let mut crc = (i as u16) << 8;
+                let mut bit = 0;
+
+                while bit < 8 {
+                    crc = if crc & 0x8000 != 0 {
+                        crc << 1 ^ POLY
+                    } else {
+                        crc << 1
+                    };
+                    bit += 1;
+                }
+
+                table[i] = crc;
+                i += 1;
+            }
+
+            table
+        };
+
+        let bytes = self.as_bytes();
+
+        let mut crc: u16 = 0xffff;
+        let mut i = 0;
+        while i < 16 {
+            crc = crc << 8 ^ TABLE[((crc >> 8) as u8 ^ bytes[i]) as usize];
+            i += 1;
+        }
+
+        crc
+    }
+
+    /// Tests if the UUID is nil (all zeros).
+    ///
+    /// This compares the UUID as a single `u128`, so it's cheaper than
+    /// comparing against [`Uuid::nil`] and can be used in const contexts.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// assert!(Uuid::nil().is_nil());
+    ///
+    /// let uuid = Uuid::parse_str("00000000-0000-0000-0000-000000000001")?;
+    ///
+    /// assert!(!uuid.is_nil());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Uuid::nil`]: #method.nil
+    pub const fn is_nil(&self) -> bool {
+        self.as_u128() == u128::MIN
+    }
+
+    /// Tests if the UUID is max (all ones).
+    ///
+    /// The max UUID is the counterpart of the nil UUID. It's greater than
+    /// any other UUID, so it can be used as an inclusive upper bound.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// assert!(Uuid::max().is_max());
+    ///
+    /// let uuid = Uuid::parse_str("ffffffff-ffff-ffff-ffff-fffffffffffe")?;
+    ///
+    /// assert!(!uuid.is_max());
+    /// assert!(uuid < Uuid::max());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(uuid_unstable)]
+    pub const fn is_max(&self) -> bool {
+        self.as_u128() == u128::MAX
+    }
+
+    /// A buffer that can be used for `encode_...` calls, that is
+    /// guaranteed to be long enough for any of the format adapters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::nil();
+    ///
+    /// assert_eq!(
+    ///     uuid.simple().encode_lower(&mut Uuid::encode_buffer()),
+    ///     "00000000000000000000000000000000"
+    /// );
+    ///
+    /// assert_eq!(
+    ///     uuid.hyphenated()
+    ///         .encode_lower(&mut Uuid::encode_buffer()),
+    ///     "00000000-0000-0000-0000-000000000000"
+    /// );
+    ///
+    /// assert_eq!(
+    ///     uuid.urn().encode_lower(&mut Uuid::encode_buffer()),
+    ///     "urn:uuid:00000000-0000-0000-0000-000000000000"
+    /// );
+    /// ```
+    pub const fn encode_buffer() -> [u8; fmt::Urn::LENGTH] {
+        [0; fmt::Urn::LENGTH]
+    }
+
+    /// If the UUID is the correct version (v1, v6, or v7) this will return
+    /// the timestamp and counter portion parsed from a V1 UUID.
+    ///
+    /// Returns `None` if the supplied UUID is not V1.
+    ///
+    /// The V1 timestamp format defined in RFC4122 specifies a 60-bit
+    /// integer representing the number of 100-nanosecond intervals
+    /// since 00:00:00.00, 15 Oct 1582.
+    ///
+    /// [`Timestamp`] offers several options for converting the raw RFC4122
+    /// value into more commonly-used formats, such as a unix timestamp.
+    ///
+    /// # Roundtripping
+    ///
+    /// This method is unlikely to roundtrip a timestamp in a UUID due to the way
     /// UUIDs encode timestamps. The timestamp returned from this method will be truncated to
     /// 100ns precision for version 1 and 6 UUIDs, and to millisecond precision for version 7 UUIDs.
     ///
@@ -969,376 +1952,1171 @@ impl Uuid {
             }
             _ => None,
         }
-    }
-}
+    }
+
+    /// If the UUID is a version 7 UUID, returns its Unix timestamp in
+    /// milliseconds.
+    ///
+    /// This reads the 48bit big-endian timestamp at the start of the UUID
+    /// directly, without going through a [`Timestamp`]. It returns `None` for
+    /// all other versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("017f22e2-79b0-7cc3-98c4-dc0c0c07398f")?;
+    ///
+    /// assert_eq!(Some(1645557742000), uuid.unix_millis());
+    ///
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// assert_eq!(None, uuid.unix_millis());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Timestamp`]: struct.Timestamp.html
+    #[cfg(uuid_unstable)]
+    pub const fn unix_millis(&self) -> Option<u64> {
+        match self.get_version() {
+            Some(Version::SortRand) => Some(timestamp::decode_unix_timestamp_millis(self)),
+            _ => None,
+        }
+    }
+
+    /// Packs a version 7 UUID into a time-ordered `u64` key.
+    ///
+    /// The 48bit Unix timestamp in milliseconds is kept in the high bits, and
+    /// the low 16 bits are the first 16 bits after the version and timestamp:
+    /// the 12 bit `rand_a` field followed by the top 4 bits of `rand_b`. Keys
+    /// sort in the same order as the timestamps of their UUIDs, which can be
+    /// useful for indexes that only support 64bit keys. It returns `None` for
+    /// all other versions.
+    ///
+    /// Keys are only approximately unique. UUIDs created in the same
+    /// millisecond differ in just 16 bits of the key, so by the birthday bound
+    /// a collision becomes likely after a few hundred random UUIDs in a
+    /// single millisecond. UUIDs that use `rand_a` as a counter, like the ones
+    /// created by [`Uuid::new_v7_with_counter`], get distinct keys for up to
+    /// 4096 UUIDs per millisecond.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("017f22e2-79b0-7cc3-98c4-dc0c0c07398f")?;
+    ///
+    /// assert_eq!(Some(0x017f_22e2_79b0_cc36), uuid.to_time_ordered_u64());
+    ///
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+    ///
+    /// assert_eq!(None, uuid.to_time_ordered_u64());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Uuid::new_v7_with_counter`]: #method.new_v7_with_counter
+    #[cfg(uuid_unstable)]
+    pub const fn to_time_ordered_u64(&self) -> Option<u64> {
+        match self.unix_millis() {
+            Some(millis) => {
+                let bytes = self.as_bytes();
+
+                let rand = ((bytes[6] & 0x0f) as u64) << 12
+                    | (bytes[7] as u64) << 4
+                    | ((bytes[8] & 0x3f) >> 2) as u64;
+
+                Some(millis << 16 | rand)
+            }
+            None => None,
+        }
+    }
+
+    /// Returns all of the metadata that can be read from the UUID.
+    ///
+    /// This collects the results of [`Uuid::get_version`],
+    /// [`Uuid::get_variant`], [`Uuid::is_nil`] and [`Uuid::get_timestamp`],
+    /// and whether the UUID is the max UUID, into a single [`UuidInfo`], along with the
+    /// node ID of version 1 and 6 UUIDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Variant, Version};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("f9168c5e-ceb2-11d1-80b4-00c04fd430c8")?;
+    /// let info = uuid.describe();
+    ///
+    /// assert_eq!(Some(Version::Mac), info.version);
+    /// assert_eq!(Variant::RFC4122, info.variant);
+    /// assert_eq!(Some([0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8]), info.node_id);
+    /// assert!(info.timestamp.is_some());
+    ///
+    /// println!("{}", info);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`UuidInfo`]: struct.UuidInfo.html
+    pub const fn describe(&self) -> UuidInfo {
+        let node_id = match self.get_version() {
+            Some(Version::Mac) => Some(self.node()),
+            #[cfg(uuid_unstable)]
+            Some(Version::SortMac) => Some(self.node()),
+            _ => None,
+        };
+
+        UuidInfo {
+            version: self.get_version(),
+            variant: self.get_variant(),
+            is_nil: self.is_nil(),
+            // `Uuid::is_max` is unstable, but the field isn't
+            is_max: self.as_u128() == u128::MAX,
+            timestamp: self.get_timestamp(),
+            node_id,
+        }
+    }
+
+    /// Feeds the UUID into a hasher as a single `u128`.
+    ///
+    /// This is what the `Hash` impl for `Uuid` does, so it produces exactly
+    /// the same hasher state. Types that implement `Hash` by hand, like a
+    /// composite key wrapping a `Uuid`, can call this directly. The two must
+    /// always stay consistent, or hash-based collections that mix them would
+    /// fail to find keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// use std::hash::{Hash, Hasher};
+    ///
+    /// struct Key {
+    ///     tenant: Uuid,
+    ///     id: u32,
+    /// }
+    ///
+    /// impl Hash for Key {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         self.tenant.hash_into(state);
+    ///         self.id.hash(state);
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    pub fn hash_into<H: hash::Hasher>(&self, state: &mut H) {
+        state.write_u128(self.as_u128());
+    }
+}
+
+impl hash::Hash for Uuid {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.hash_into(state)
+    }
+}
+
+impl Default for Uuid {
+    #[inline]
+    fn default() -> Self {
+        Uuid::nil()
+    }
+}
+
+impl AsRef<[u8]> for Uuid {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// A [`Uuid`] that's aligned to 16 bytes.
+///
+/// A `Uuid` is just 16 bytes, so it only has an alignment of 1. Wrapping it
+/// in an `AlignedUuid` raises the alignment to 16 without changing its size,
+/// so every element of an array or `Vec` of them starts on a 16 byte
+/// boundary. That allows batch processing code to use aligned SIMD loads
+/// like the ones in SSE and AVX.
+///
+/// The size and alignment are both 16 bytes. Converting between `Uuid` and
+/// `AlignedUuid` is free, apart from copying an unaligned value into an
+/// aligned place in memory.
+///
+/// # Examples
+///
+/// ```
+/// # use uuid::{AlignedUuid, Uuid};
+/// let uuids = [AlignedUuid::from(Uuid::NAMESPACE_DNS); 4];
+///
+/// assert_eq!(0, uuids.as_ptr() as usize % 16);
+/// assert_eq!(Uuid::NAMESPACE_DNS, Uuid::from(uuids[0]));
+/// ```
+///
+/// [`Uuid`]: struct.Uuid.html
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(C, align(16))]
+pub struct AlignedUuid(pub Uuid);
+
+impl From<Uuid> for AlignedUuid {
+    #[inline]
+    fn from(uuid: Uuid) -> Self {
+        AlignedUuid(uuid)
+    }
+}
+
+impl From<AlignedUuid> for Uuid {
+    #[inline]
+    fn from(aligned: AlignedUuid) -> Self {
+        aligned.0
+    }
+}
+
+#[cfg(feature = "serde")]
+pub mod serde {
+    //! Adapters for alternative `serde` formats.
+    //!
+    //! This module contains adapters you can use with [`#[serde(with)]`](https://serde.rs/field-attrs.html#with)
+    //! to change the way a [`Uuid`](../struct.Uuid.html) is serialized
+    //! and deserialized.
+
+    pub use crate::external::serde_support::{braced, compact, simple};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::std::string::{String, ToString};
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    macro_rules! check {
+        ($buf:ident, $format:expr, $target:expr, $len:expr, $cond:expr) => {
+            $buf.clear();
+            write!($buf, $format, $target).unwrap();
+            assert!($buf.len() == $len);
+            assert!($buf.chars().all($cond), "{}", $buf);
+        };
+    }
+
+    pub const fn new() -> Uuid {
+        Uuid::from_bytes([
+            0xF9, 0x16, 0x8C, 0x5E, 0xCE, 0xB2, 0x4F, 0xAA, 0xB6, 0xBF, 0x32, 0x9B, 0xF3, 0x9F,
+            0xA1, 0xE4,
+        ])
+    }
+
+    pub const fn new2() -> Uuid {
+        Uuid::from_bytes([
+            0xF9, 0x16, 0x8C, 0x5E, 0xCE, 0xB2, 0x4F, 0xAB, 0xB6, 0xBF, 0x32, 0x9B, 0xF3, 0x9F,
+            0xA1, 0xE4,
+        ])
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_uuid_compare() {
+        let uuid1 = new();
+        let uuid2 = new2();
+
+        assert_eq!(uuid1, uuid1);
+        assert_eq!(uuid2, uuid2);
+
+        assert_ne!(uuid1, uuid2);
+        assert_ne!(uuid2, uuid1);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_uuid_default() {
+        let default_uuid = Uuid::default();
+        let nil_uuid = Uuid::nil();
+
+        assert_eq!(default_uuid, nil_uuid);
+
+        #[derive(Default)]
+        struct Record {
+            id: Uuid,
+        }
+
+        assert_eq!(Record::default().id, Uuid::nil());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_uuid_display() {
+        use crate::std::fmt::Write;
+
+        let uuid = new();
+        let s = uuid.to_string();
+        let mut buffer = String::new();
+
+        assert_eq!(s, uuid.hyphenated().to_string());
+
+        check!(buffer, "{}", uuid, 36, |c| c.is_lowercase()
+            || c.is_ascii_digit()
+            || c == '-');
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_uuid_lowerhex() {
+        use crate::std::fmt::Write;
+
+        let mut buffer = String::new();
+        let uuid = new();
+
+        check!(buffer, "{:x}", uuid, 36, |c| c.is_lowercase()
+            || c.is_ascii_digit()
+            || c == '-');
+    }
+
+    // noinspection RsAssertEqual
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_uuid_operator_eq() {
+        let uuid1 = new();
+        let uuid1_dup = uuid1;
+        let uuid2 = new2();
+
+        assert!(uuid1 == uuid1);
+        assert!(uuid1 == uuid1_dup);
+        assert!(uuid1_dup == uuid1);
+
+        assert!(uuid1 != uuid2);
+        assert!(uuid2 != uuid1);
+        assert!(uuid1_dup != uuid2);
+        assert!(uuid2 != uuid1_dup);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_uuid_to_string() {
+        use crate::std::fmt::Write;
+
+        let uuid = new();
+        let s = uuid.to_string();
+        let mut buffer = String::new();
+
+        assert_eq!(s.len(), 36);
+
+        check!(buffer, "{}", s, 36, |c| c.is_lowercase()
+            || c.is_ascii_digit()
+            || c == '-');
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_non_conforming() {
+        let from_bytes =
+            Uuid::from_bytes([4, 54, 67, 12, 43, 2, 2, 76, 32, 50, 87, 5, 1, 33, 43, 87]);
+
+        assert_eq!(from_bytes.get_version(), None);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_nil() {
+        let nil = Uuid::nil();
+        let not_nil = new();
+
+        assert!(nil.is_nil());
+        assert!(!not_nil.is_nil());
+
+        for bit in 0..128 {
+            assert!(!Uuid::from_u128(1 << bit).is_nil());
+        }
+
+        const IS_NIL: [bool; 2] = [Uuid::nil().is_nil(), Uuid::NAMESPACE_DNS.is_nil()];
+        assert_eq!(IS_NIL, [true, false]);
+
+        assert_eq!(nil.get_version(), Some(Version::Nil));
+        assert_eq!(not_nil.get_version(), Some(Version::Random));
+
+        assert_eq!(nil, Builder::from_bytes([0; 16]).with_version(Version::Nil).into_uuid());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_describe() {
+        let info = Uuid::nil().describe();
+
+        assert_eq!(info.version, Some(Version::Nil));
+        assert_eq!(info.variant, Variant::NCS);
+        assert!(info.is_nil);
+        assert!(!info.is_max);
+        assert_eq!(info.timestamp, None);
+        assert_eq!(info.node_id, None);
+
+        let info = new().describe();
+
+        assert_eq!(info.version, Some(Version::Random));
+        assert_eq!(info.variant, Variant::RFC4122);
+        assert!(!info.is_nil);
+        assert!(!info.is_max);
+        assert_eq!(info.timestamp, None);
+        assert_eq!(info.node_id, None);
+
+        let uuid = Uuid::from_u128(0x6ba7b810_9dad_11d1_80b4_00c04fd430c8);
+        let info = uuid.describe();
+
+        assert_eq!(info.version, Some(Version::Mac));
+        assert_eq!(info.variant, Variant::RFC4122);
+        assert!(!info.is_nil);
+        assert_eq!(info.timestamp, uuid.get_timestamp());
+        assert!(info.timestamp.is_some());
+        assert_eq!(info.node_id, Some([0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8]));
+
+        let info = Uuid::from_bytes([0xff; 16]).describe();
+
+        assert_eq!(info.variant, Variant::Future);
+        assert!(info.is_max);
+    }
+
+    #[test]
+    #[cfg(uuid_unstable)]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_describe_unstable() {
+        let info = Uuid::max().describe();
+
+        assert_eq!(info.version, Some(Version::Max));
+        assert!(info.is_max);
+
+        let uuid = Builder::from_unix_timestamp_millis(1_645_557_742_000, &[0; 10]).into_uuid();
+        let info = uuid.describe();
+
+        assert_eq!(info.version, Some(Version::SortRand));
+        assert_eq!(
+            info.timestamp.map(|ts| ts.to_unix()),
+            Some((1_645_557_742, 0))
+        );
+        assert_eq!(info.node_id, None);
+
+        let uuid = Uuid::from_u128(0x1d19dad6_ba7b_6810_80b4_00c04fd430c8);
+        let info = uuid.describe();
+
+        assert_eq!(info.version, Some(Version::SortMac));
+        assert!(info.timestamp.is_some());
+        assert_eq!(info.node_id, Some([0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8]));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_describe_display() {
+        let uuid = Uuid::from_u128(0x6ba7b810_9dad_11d1_80b4_00c04fd430c8);
+
+        assert_eq!(
+            uuid.describe().to_string(),
+            "version: Mac\n\
+             variant: RFC4122\n\
+             nil: false\n\
+             max: false\n\
+             timestamp: 886630433.151182400\n\
+             node id: 00:c0:4f:d4:30:c8",
+        );
+
+        assert_eq!(
+            new().describe().to_string(),
+            "version: Random\n\
+             variant: RFC4122\n\
+             nil: false\n\
+             max: false\n\
+             timestamp: none\n\
+             node id: none",
+        );
+    }
+
+    #[test]
+    #[cfg(uuid_unstable)]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_max() {
+        let max = Uuid::max();
+        let not_max = new();
+
+        assert!(max.is_max());
+        assert!(!not_max.is_max());
+
+        assert_eq!(max.get_version(), Some(Version::Max));
+        assert_eq!(not_max.get_version(), Some(Version::Random));
+
+        assert_eq!(max, Builder::from_bytes([0xff; 16]).with_version(Version::Max).into_uuid());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_predefined_namespaces() {
+        assert_eq!(
+            Uuid::NAMESPACE_DNS.hyphenated().to_string(),
+            "6ba7b810-9dad-11d1-80b4-00c04fd430c8"
+        );
+        assert_eq!(
+            Uuid::NAMESPACE_URL.hyphenated().to_string(),
+            "6ba7b811-9dad-11d1-80b4-00c04fd430c8"
+        );
+        assert_eq!(
+            Uuid::NAMESPACE_OID.hyphenated().to_string(),
+            "6ba7b812-9dad-11d1-80b4-00c04fd430c8"
+        );
+        assert_eq!(
+            Uuid::NAMESPACE_X500.hyphenated().to_string(),
+            "6ba7b814-9dad-11d1-80b4-00c04fd430c8"
+        );
+    }
+
+    #[cfg(feature = "v3")]
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_get_version_v3() {
+        let uuid = Uuid::new_v3(&Uuid::NAMESPACE_DNS, "rust-lang.org".as_bytes());
+
+        assert_eq!(uuid.get_version().unwrap(), Version::Md5);
+        assert_eq!(uuid.get_version_num(), 3);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_get_version_from_builder() {
+        for (version, num) in [
+            (Version::Mac, 1),
+            (Version::Dce, 2),
+            (Version::Md5, 3),
+            (Version::Random, 4),
+            (Version::Sha1, 5),
+        ] {
+            let uuid = Builder::from_random_bytes([0xa5; 16])
+                .with_version(version)
+                .into_uuid();
+
+            assert_eq!(uuid.get_version(), Some(version));
+            assert_eq!(uuid.get_version_num(), num);
+        }
+
+        // Unrecognized version nibbles are still readable
+        let mut bytes = [0; 16];
+        bytes[6] = 0xb0;
+        let uuid = Uuid::from_bytes(bytes);
+
+        assert_eq!(uuid.get_version(), None);
+        assert_eq!(uuid.get_version_num(), 11);
+
+        const VERSION: Option<Version> = Uuid::NAMESPACE_DNS.get_version();
+        assert_eq!(VERSION, Some(Version::Mac));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_get_variant() {
+        let uuid1 = new();
+        let uuid2 = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap();
+        let uuid3 = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        let uuid4 = Uuid::parse_str("936DA01F9ABD4d9dC0C702AF85C822A8").unwrap();
+        let uuid5 = Uuid::parse_str("F9168C5E-CEB2-4faa-D6BF-329BF39FA1E4").unwrap();
+        let uuid6 = Uuid::parse_str("f81d4fae-7dec-11d0-7765-00a0c91e6bf6").unwrap();
+
+        assert_eq!(uuid1.get_variant(), Variant::RFC4122);
+        assert_eq!(uuid2.get_variant(), Variant::RFC4122);
+        assert_eq!(uuid3.get_variant(), Variant::RFC4122);
+        assert_eq!(uuid4.get_variant(), Variant::Microsoft);
+        assert_eq!(uuid5.get_variant(), Variant::Microsoft);
+        assert_eq!(uuid6.get_variant(), Variant::NCS);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_get_variant_matches_builder() {
+        for variant in [
+            Variant::NCS,
+            Variant::RFC4122,
+            Variant::Microsoft,
+            Variant::Future,
+        ] {
+            for fill in [0x00, 0xff] {
+                let uuid = Builder::from_bytes([fill; 16])
+                    .with_variant(variant)
+                    .into_uuid();
+
+                assert_eq!(uuid.get_variant(), variant);
+            }
+        }
+
+        for byte in 0..=255u8 {
+            let mut bytes = [0; 16];
+            bytes[8] = byte;
+
+            let expected = match byte >> 5 {
+                0b000..=0b011 => Variant::NCS,
+                0b100 | 0b101 => Variant::RFC4122,
+                0b110 => Variant::Microsoft,
+                _ => Variant::Future,
+            };
+
+            assert_eq!(Uuid::from_bytes(bytes).get_variant(), expected);
+        }
+
+        const VARIANT: Variant = Uuid::NAMESPACE_DNS.get_variant();
+        assert_eq!(VARIANT, Variant::RFC4122);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_to_simple_string() {
+        let uuid1 = new();
+        let s = uuid1.simple().to_string();
+
+        assert_eq!(s.len(), 32);
+        assert!(s.chars().all(|c| c.is_ascii_hexdigit()));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_hyphenated_string() {
+        let uuid1 = new();
+        let s = uuid1.hyphenated().to_string();
+
+        assert_eq!(36, s.len());
+        assert!(s.chars().all(|c| c.is_ascii_hexdigit() || c == '-'));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_upper_lower_hex() {
+        use std::fmt::Write;
+
+        let mut buf = String::new();
+        let u = new();
+
+        macro_rules! check {
+            ($buf:ident, $format:expr, $target:expr, $len:expr, $cond:expr) => {
+                $buf.clear();
+                write!($buf, $format, $target).unwrap();
+                assert_eq!($len, buf.len());
+                assert!($buf.chars().all($cond), "{}", $buf);
+            };
+        }
 
-impl Default for Uuid {
-    #[inline]
-    fn default() -> Self {
-        Uuid::nil()
-    }
-}
+        check!(buf, "{:x}", u, 36, |c| c.is_lowercase()
+            || c.is_ascii_digit()
+            || c == '-');
+        check!(buf, "{:X}", u, 36, |c| c.is_uppercase()
+            || c.is_ascii_digit()
+            || c == '-');
+        check!(buf, "{:#x}", u, 32, |c| c.is_lowercase() || c.is_ascii_digit());
+        check!(buf, "{:#X}", u, 32, |c| c.is_uppercase() || c.is_ascii_digit());
 
-impl AsRef<[u8]> for Uuid {
-    #[inline]
-    fn as_ref(&self) -> &[u8] {
-        &self.0
+        check!(buf, "{:X}", u.hyphenated(), 36, |c| c.is_uppercase()
+            || c.is_ascii_digit()
+            || c == '-');
+        check!(buf, "{:X}", u.simple(), 32, |c| c.is_uppercase()
+            || c.is_ascii_digit());
+        check!(buf, "{:#X}", u.hyphenated(), 36, |c| c.is_uppercase()
+            || c.is_ascii_digit()
+            || c == '-');
+        check!(buf, "{:#X}", u.simple(), 32, |c| c.is_uppercase()
+            || c.is_ascii_digit());
+
+        check!(buf, "{:x}", u.hyphenated(), 36, |c| c.is_lowercase()
+            || c.is_ascii_digit()
+            || c == '-');
+        check!(buf, "{:x}", u.simple(), 32, |c| c.is_lowercase()
+            || c.is_ascii_digit());
+        check!(buf, "{:#x}", u.hyphenated(), 36, |c| c.is_lowercase()
+            || c.is_ascii_digit()
+            || c == '-');
+        check!(buf, "{:#x}", u.simple(), 32, |c| c.is_lowercase()
+            || c.is_ascii_digit());
     }
-}
 
-#[cfg(feature = "serde")]
-pub mod serde {
-    //! Adapters for alternative `serde` formats.
-    //!
-    //! This module contains adapters you can use with [`#[serde(with)]`](https://serde.rs/field-attrs.html#with)
-    //! to change the way a [`Uuid`](../struct.Uuid.html) is serialized
-    //! and deserialized.
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_to_urn_string() {
+        let uuid1 = new();
+        let ss = uuid1.urn().to_string();
+        let s = &ss[9..];
 
-    pub use crate::external::serde_support::compact;
-}
+        assert!(ss.starts_with("urn:uuid:"));
+        assert_eq!(s.len(), 36);
+        assert!(s.chars().all(|c| c.is_ascii_hexdigit() || c == '-'));
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_to_simple_string_matching() {
+        let uuid1 = new();
 
-    use crate::std::string::{String, ToString};
+        let hs = uuid1.hyphenated().to_string();
+        let ss = uuid1.simple().to_string();
 
-    #[cfg(target_arch = "wasm32")]
-    use wasm_bindgen_test::*;
+        let hsn = hs.chars().filter(|&c| c != '-').collect::<String>();
 
-    macro_rules! check {
-        ($buf:ident, $format:expr, $target:expr, $len:expr, $cond:expr) => {
-            $buf.clear();
-            write!($buf, $format, $target).unwrap();
-            assert!($buf.len() == $len);
-            assert!($buf.chars().all($cond), "{}", $buf);
-        };
+        assert_eq!(hsn, ss);
     }
 
-    pub const fn new() -> Uuid {
-        Uuid::from_bytes([
-            0xF9, 0x16, 0x8C, 0x5E, 0xCE, 0xB2, 0x4F, 0xAA, 0xB6, 0xBF, 0x32, 0x9B, 0xF3, 0x9F,
-            0xA1, 0xE4,
-        ])
-    }
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_string_roundtrip() {
+        let uuid = new();
 
-    pub const fn new2() -> Uuid {
-        Uuid::from_bytes([
-            0xF9, 0x16, 0x8C, 0x5E, 0xCE, 0xB2, 0x4F, 0xAB, 0xB6, 0xBF, 0x32, 0x9B, 0xF3, 0x9F,
-            0xA1, 0xE4,
-        ])
+        let hs = uuid.hyphenated().to_string();
+        let uuid_hs = Uuid::parse_str(&hs).unwrap();
+        assert_eq!(uuid_hs, uuid);
+
+        let ss = uuid.to_string();
+        let uuid_ss = Uuid::parse_str(&ss).unwrap();
+        assert_eq!(uuid_ss, uuid);
     }
 
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
-    fn test_uuid_compare() {
-        let uuid1 = new();
-        let uuid2 = new2();
+    fn test_from_fields() {
+        let d1: u32 = 0xa1a2a3a4;
+        let d2: u16 = 0xb1b2;
+        let d3: u16 = 0xc1c2;
+        let d4 = [0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8];
 
-        assert_eq!(uuid1, uuid1);
-        assert_eq!(uuid2, uuid2);
+        let u = Uuid::from_fields(d1, d2, d3, &d4);
 
-        assert_ne!(uuid1, uuid2);
-        assert_ne!(uuid2, uuid1);
+        let expected = "a1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8";
+        let result = u.simple().to_string();
+        assert_eq!(result, expected);
     }
 
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
-    fn test_uuid_default() {
-        let default_uuid = Uuid::default();
-        let nil_uuid = Uuid::nil();
+    fn test_from_fields_le() {
+        let d1: u32 = 0xa4a3a2a1;
+        let d2: u16 = 0xb2b1;
+        let d3: u16 = 0xc2c1;
+        let d4 = [0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8];
 
-        assert_eq!(default_uuid, nil_uuid);
+        let u = Uuid::from_fields_le(d1, d2, d3, &d4);
+
+        let expected = "a1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8";
+        let result = u.simple().to_string();
+        assert_eq!(result, expected);
     }
 
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
-    fn test_uuid_display() {
-        use crate::std::fmt::Write;
+    fn test_builder_bytes() {
+        let mut builder = Builder::from_bytes(*new().as_bytes());
 
-        let uuid = new();
-        let s = uuid.to_string();
-        let mut buffer = String::new();
+        assert_eq!(builder.as_bytes(), new().as_bytes());
+        assert_eq!(builder.bytes(), new().into_bytes());
 
-        assert_eq!(s, uuid.hyphenated().to_string());
+        builder.set_version(Version::Sha1);
 
-        check!(buffer, "{}", uuid, 36, |c| c.is_lowercase()
-            || c.is_digit(10)
-            || c == '-');
+        assert_eq!(builder.as_bytes()[6] >> 4, 5);
+        assert_eq!(builder.bytes(), builder.into_uuid().into_bytes());
     }
 
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
-    fn test_uuid_lowerhex() {
-        use crate::std::fmt::Write;
+    fn test_builder_time_fields() {
+        let expected = Uuid::parse_str("6ba7b810-9dad-11d1-80b4-00c04fd430c8").unwrap();
+
+        // The version can be set before or after the high field
+        let before = Builder::from_bytes(*Uuid::NAMESPACE_DNS.as_bytes())
+            .with_time_low(0)
+            .with_time_mid(0)
+            .with_time_high(0xffff)
+            .with_version(Version::Mac)
+            .with_time_high(0x01d1)
+            .with_time_mid(0x9dad)
+            .with_time_low(0x6ba7b810)
+            .into_uuid();
+
+        // Setting the fields of an existing UUID keeps its version
+        let mut builder = Builder::from_bytes(*expected.as_bytes());
+        builder.set_time_low(0).set_time_mid(0).set_time_high(0);
+
+        assert_eq!(builder.as_uuid().as_u128(), 0x1000_80b4_00c0_4fd4_30c8_u128);
+
+        builder
+            .set_time_high(0xf1d1)
+            .set_time_low(0x6ba7b810)
+            .set_time_mid(0x9dad);
+        let after = builder.into_uuid();
+
+        assert_eq!(before, expected);
+        assert_eq!(after, expected);
+        assert_eq!(before.time_low(), 0x6ba7b810);
+        assert_eq!(before.time_mid(), 0x9dad);
+        assert_eq!(before.time_hi_and_version(), 0x11d1);
+        assert_eq!(before.get_variant(), Variant::RFC4122);
+    }
 
-        let mut buffer = String::new();
-        let uuid = new();
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_aligned_uuid() {
+        use crate::std::mem;
 
-        check!(buffer, "{:x}", uuid, 36, |c| c.is_lowercase()
-            || c.is_digit(10)
-            || c == '-');
+        assert_eq!(mem::size_of::<AlignedUuid>(), 16);
+        assert_eq!(mem::align_of::<AlignedUuid>(), 16);
+        assert_eq!(mem::size_of::<[AlignedUuid; 4]>(), 64);
+
+        let mut rng = xorshift(1);
+        for _ in 0..100 {
+            let uuid = Uuid::from_bytes(rng());
+            let aligned = AlignedUuid::from(uuid);
+
+            assert_eq!(aligned.0, uuid);
+            assert_eq!(Uuid::from(aligned), uuid);
+        }
+
+        let uuids = [AlignedUuid::from(new()), AlignedUuid::from(new2())];
+        for aligned in uuids.iter() {
+            assert_eq!(aligned as *const AlignedUuid as usize % 16, 0);
+        }
+        assert!(uuids[0] < uuids[1]);
     }
 
-    // noinspection RsAssertEqual
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
-    fn test_uuid_operator_eq() {
-        let uuid1 = new();
-        let uuid1_dup = uuid1.clone();
-        let uuid2 = new2();
+    fn test_from_fields_slice() {
+        use crate::error::ErrorKind;
 
-        assert!(uuid1 == uuid1);
-        assert!(uuid1 == uuid1_dup);
-        assert!(uuid1_dup == uuid1);
+        let d1: u32 = 0xa1a2a3a4;
+        let d2: u16 = 0xb1b2;
+        let d3: u16 = 0xc1c2;
+        let d4 = [0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8];
 
-        assert!(uuid1 != uuid2);
-        assert!(uuid2 != uuid1);
-        assert!(uuid1_dup != uuid2);
-        assert!(uuid2 != uuid1_dup);
+        assert_eq!(
+            Uuid::from_fields_slice(d1, d2, d3, &d4),
+            Ok(Uuid::from_fields(d1, d2, d3, &d4))
+        );
+        assert_eq!(
+            Uuid::from_fields_le_slice(d1, d2, d3, &d4),
+            Ok(Uuid::from_fields_le(d1, d2, d3, &d4))
+        );
+
+        let long = [0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8, 0xd9];
+
+        for len in [0, 7, 9] {
+            assert_eq!(
+                Uuid::from_fields_slice(d1, d2, d3, &long[..len]),
+                Err(Error(ErrorKind::FieldLength { len }))
+            );
+            assert_eq!(
+                Uuid::from_fields_le_slice(d1, d2, d3, &long[..len]),
+                Err(Error(ErrorKind::FieldLength { len }))
+            );
+        }
     }
 
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
-    fn test_uuid_to_string() {
-        use crate::std::fmt::Write;
+    fn test_java_cmp() {
+        use crate::std::cmp::Ordering;
+
+        let pairs = [
+            (Uuid::nil(), Uuid::nil(), Ordering::Equal),
+            (Uuid::nil(), Uuid::from_u128(1), Ordering::Less),
+            // The top bit of the high half makes it negative
+            (
+                Uuid::from_u128(0x8000 << 112),
+                Uuid::from_u128(0x7fff << 112),
+                Ordering::Less,
+            ),
+            (Uuid::from_u128(0x8000 << 112), Uuid::nil(), Ordering::Less),
+            // The low half is only compared when the high halves are equal
+            (
+                Uuid::from_u128(1 << 64 | 1 << 63),
+                Uuid::from_u128(1 << 64),
+                Ordering::Less,
+            ),
+            (Uuid::from_u128(u128::MAX), Uuid::nil(), Ordering::Less),
+        ];
 
-        let uuid = new();
-        let s = uuid.to_string();
-        let mut buffer = String::new();
+        for (a, b, expected) in pairs.iter() {
+            assert_eq!(a.java_cmp(b), *expected);
+            assert_eq!(b.java_cmp(a), expected.reverse());
+        }
 
-        assert_eq!(s.len(), 36);
+        // Values without the top bit set in either half sort the same way
+        assert_eq!(new().java_cmp(&new2()), new().cmp(&new2()));
+    }
 
-        check!(buffer, "{}", s, 36, |c| c.is_lowercase()
-            || c.is_digit(10)
-            || c == '-');
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_cmp_bytes() {
+        use crate::std::cmp::Ordering;
+
+        let pairs = [
+            (Uuid::nil(), Uuid::nil(), Ordering::Equal),
+            (Uuid::nil(), Uuid::from_u128(1), Ordering::Less),
+            (
+                Uuid::from_u128(1 << 120),
+                Uuid::from_u128(u64::MAX as u128),
+                Ordering::Greater,
+            ),
+            (
+                Uuid::from_u128(0x00ff << 112),
+                Uuid::from_u128(0x0100 << 112),
+                Ordering::Less,
+            ),
+            (
+                Uuid::from_u128(u128::MAX),
+                Uuid::from_u128(u128::MAX - 1),
+                Ordering::Greater,
+            ),
+            (new(), new2(), Ordering::Less),
+        ];
+
+        for (a, b, expected) in pairs.iter() {
+            assert_eq!(a.cmp_bytes(b), *expected);
+            assert_eq!(b.cmp_bytes(a), expected.reverse());
+            assert_eq!(a.cmp(b), *expected);
+            assert_eq!(a.as_bytes().cmp(b.as_bytes()), *expected);
+            assert_eq!(a.to_string().cmp(&b.to_string()), *expected);
+        }
     }
 
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
-    fn test_non_conforming() {
-        let from_bytes =
-            Uuid::from_bytes([4, 54, 67, 12, 43, 2, 2, 76, 32, 50, 87, 5, 1, 33, 43, 87]);
+    fn test_cmp_bytes_matches_ord() {
+        use crate::std::vec::Vec;
 
-        assert_eq!(from_bytes.get_version(), None);
+        let mut random_bytes = xorshift(0x5eed);
+
+        let mut uuids: Vec<Uuid> = (0..1000)
+            .map(|_| Uuid::from_bytes(random_bytes()))
+            .collect();
+        uuids.sort_by(Uuid::cmp_bytes);
+
+        for pair in uuids.windows(2) {
+            assert!(pair[0] <= pair[1]);
+            assert!(pair[0].as_bytes() <= pair[1].as_bytes());
+        }
     }
 
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
-    fn test_nil() {
-        let nil = Uuid::nil();
-        let not_nil = new();
+    fn test_ord_sorts_by_bytes() {
+        use crate::std::{collections::BTreeSet, vec::Vec};
+
+        let expected = [
+            Uuid::nil(),
+            Uuid::from_u128(0x00000000_0000_0000_0000_000000000001),
+            Uuid::from_u128(0x00000000_0000_0000_0000_000000000100),
+            Uuid::from_u128(0x0000000f_ffff_ffff_ffff_ffffffffffff),
+            Uuid::from_u128(0x01808be7_386c_7000_8000_000000000000),
+            Uuid::from_u128(0x01808be7_386d_7000_8000_000000000000),
+            Uuid::from_u128(0xa1a2a3a4_b1b2_c1c2_d1d2_d3d4d5d6d7d8),
+            Uuid::from_u128(0xffffffff_0000_0000_0000_000000000000),
+            Uuid::from_bytes([0xff; 16]),
+        ];
 
-        assert!(nil.is_nil());
-        assert!(!not_nil.is_nil());
+        let mut shuffled: Vec<Uuid> = [6, 2, 8, 0, 5, 3, 7, 1, 4]
+            .iter()
+            .map(|&i| expected[i])
+            .collect();
+        shuffled.sort();
 
-        assert_eq!(nil.get_version(), Some(Version::Nil));
-        assert_eq!(not_nil.get_version(), Some(Version::Random));
+        assert_eq!(&expected[..], &shuffled[..]);
 
-        assert_eq!(nil, Builder::from_bytes([0; 16]).with_version(Version::Nil).into_uuid());
+        let set: BTreeSet<Uuid> = expected.iter().rev().copied().collect();
+        assert!(set.iter().eq(expected.iter()));
     }
 
     #[test]
-    #[cfg(uuid_unstable)]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
-    fn test_max() {
-        let max = Uuid::max();
-        let not_max = new();
+    fn test_u128_roundtrip_random() {
+        let mut random_bytes = xorshift(0x1dea);
 
-        assert!(max.is_max());
-        assert!(!not_max.is_max());
+        for _ in 0..10_000 {
+            let uuid = Uuid::from_bytes(random_bytes());
+            assert_eq!(Uuid::from_u128(uuid.as_u128()), uuid);
 
-        assert_eq!(max.get_version(), Some(Version::Max));
-        assert_eq!(not_max.get_version(), Some(Version::Random));
+            let v = u128::from_ne_bytes(random_bytes());
+            assert_eq!(Uuid::from_u128(v).as_u128(), v);
 
-        assert_eq!(max, Builder::from_bytes([0xff; 16]).with_version(Version::Max).into_uuid());
+            assert_eq!(Uuid::from_u128_le(uuid.to_u128_le()), uuid);
+            assert_eq!(uuid.to_u128_le(), uuid.as_u128().swap_bytes());
+        }
     }
 
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
-    fn test_predefined_namespaces() {
-        assert_eq!(
-            Uuid::NAMESPACE_DNS.hyphenated().to_string(),
-            "6ba7b810-9dad-11d1-80b4-00c04fd430c8"
-        );
-        assert_eq!(
-            Uuid::NAMESPACE_URL.hyphenated().to_string(),
-            "6ba7b811-9dad-11d1-80b4-00c04fd430c8"
-        );
-        assert_eq!(
-            Uuid::NAMESPACE_OID.hyphenated().to_string(),
-            "6ba7b812-9dad-11d1-80b4-00c04fd430c8"
-        );
+    fn test_split_at_bit() {
+        let uuid = new();
+        let value = uuid.as_u128();
+
+        assert_eq!(uuid.split_at_bit(0), (0, value));
+        assert_eq!(uuid.split_at_bit(128), (value, 0));
         assert_eq!(
-            Uuid::NAMESPACE_X500.hyphenated().to_string(),
-            "6ba7b814-9dad-11d1-80b4-00c04fd430c8"
+            uuid.split_at_bit(64),
+            (value >> 64, value & u64::MAX as u128)
         );
+        assert_eq!(uuid.split_at_bit(1), (1, value & (u128::MAX >> 1)));
+        assert_eq!(uuid.split_at_bit(127), (value >> 1, 0));
+
+        for bit in 1..128 {
+            let (prefix, suffix) = uuid.split_at_bit(bit);
+
+            assert!(prefix < 1 << bit);
+            assert!(suffix < 1 << (128 - bit));
+            assert_eq!(prefix << (128 - bit) | suffix, value);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_at_bit_out_of_range() {
+        let _ = new().split_at_bit(129);
     }
 
-    #[cfg(feature = "v3")]
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
-    fn test_get_version_v3() {
-        let uuid = Uuid::new_v3(&Uuid::NAMESPACE_DNS, "rust-lang.org".as_bytes());
+    #[cfg(uuid_unstable)]
+    fn test_unix_millis() {
+        let uuid = Builder::from_unix_timestamp_millis(1_645_557_742_123, &[0xff; 10]).into_uuid();
 
-        assert_eq!(uuid.get_version().unwrap(), Version::Md5);
-        assert_eq!(uuid.get_version_num(), 3);
+        assert_eq!(uuid.unix_millis(), Some(1_645_557_742_123));
+
+        let uuid = Builder::from_unix_timestamp_millis(0xffff_ffff_ffff, &[0; 10]).into_uuid();
+
+        assert_eq!(uuid.unix_millis(), Some(0xffff_ffff_ffff));
     }
 
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
-    fn test_get_variant() {
-        let uuid1 = new();
-        let uuid2 = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap();
-        let uuid3 = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
-        let uuid4 = Uuid::parse_str("936DA01F9ABD4d9dC0C702AF85C822A8").unwrap();
-        let uuid5 = Uuid::parse_str("F9168C5E-CEB2-4faa-D6BF-329BF39FA1E4").unwrap();
-        let uuid6 = Uuid::parse_str("f81d4fae-7dec-11d0-7765-00a0c91e6bf6").unwrap();
+    #[cfg(uuid_unstable)]
+    fn test_unix_millis_not_v7() {
+        let uuid = Builder::from_random_bytes([0xff; 16]).into_uuid();
 
-        assert_eq!(uuid1.get_variant(), Variant::RFC4122);
-        assert_eq!(uuid2.get_variant(), Variant::RFC4122);
-        assert_eq!(uuid3.get_variant(), Variant::RFC4122);
-        assert_eq!(uuid4.get_variant(), Variant::Microsoft);
-        assert_eq!(uuid5.get_variant(), Variant::Microsoft);
-        assert_eq!(uuid6.get_variant(), Variant::NCS);
+        assert_eq!(uuid.get_version(), Some(Version::Random));
+        assert_eq!(uuid.unix_millis(), None);
+
+        let uuid = Builder::from_custom_bytes(*uuid.as_bytes()).into_uuid();
+
+        assert_eq!(uuid.unix_millis(), None);
+        assert_eq!(Uuid::nil().unix_millis(), None);
+        assert_eq!(Uuid::max().unix_millis(), None);
     }
 
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
-    fn test_to_simple_string() {
-        let uuid1 = new();
-        let s = uuid1.simple().to_string();
+    #[cfg(uuid_unstable)]
+    fn test_to_time_ordered_u64() {
+        let uuid1 = Builder::from_unix_timestamp_millis(1_645_557_742_123, &[0xff; 10]).into_uuid();
+        let uuid2 = Builder::from_unix_timestamp_millis(1_645_557_742_124, &[0x00; 10]).into_uuid();
 
-        assert_eq!(s.len(), 32);
-        assert!(s.chars().all(|c| c.is_digit(16)));
+        let key1 = uuid1.to_time_ordered_u64().unwrap();
+        let key2 = uuid2.to_time_ordered_u64().unwrap();
+
+        assert_eq!(key1, 1_645_557_742_123 << 16 | 0xffff);
+        assert_eq!(key2, 1_645_557_742_124 << 16);
+        assert!(key1 < key2);
+
+        assert_eq!(Uuid::nil().to_time_ordered_u64(), None);
+        assert_eq!(new().to_time_ordered_u64(), None);
     }
 
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
-    fn test_hyphenated_string() {
-        let uuid1 = new();
-        let s = uuid1.hyphenated().to_string();
+    fn test_as_slice() {
+        let u = new();
 
-        assert_eq!(36, s.len());
-        assert!(s.chars().all(|c| c.is_digit(16) || c == '-'));
+        assert_eq!(u.as_slice(), &u.as_bytes()[..]);
+        assert_eq!(u.as_slice(), u.as_ref());
     }
 
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
-    fn test_upper_lower_hex() {
-        use std::fmt::Write;
-
-        let mut buf = String::new();
-        let u = new();
+    fn test_fold_u64() {
+        use crate::std::vec::Vec;
 
-        macro_rules! check {
-            ($buf:ident, $format:expr, $target:expr, $len:expr, $cond:expr) => {
-                $buf.clear();
-                write!($buf, $format, $target).unwrap();
-                assert_eq!($len, buf.len());
-                assert!($buf.chars().all($cond), "{}", $buf);
-            };
-        }
+        assert_eq!(Uuid::nil().fold_u64(), 0);
+        assert_eq!(Uuid::from_u128(u128::MAX).fold_u64(), 0);
+        assert_eq!(Uuid::from_u64_pair(1, 2).fold_u64(), 3);
 
-        check!(buf, "{:x}", u, 36, |c| c.is_lowercase()
-            || c.is_digit(10)
-            || c == '-');
-        check!(buf, "{:X}", u, 36, |c| c.is_uppercase()
-            || c.is_digit(10)
-            || c == '-');
-        check!(buf, "{:#x}", u, 32, |c| c.is_lowercase() || c.is_digit(10));
-        check!(buf, "{:#X}", u, 32, |c| c.is_uppercase() || c.is_digit(10));
+        let mut random_bytes = xorshift(0xf01d);
 
-        check!(buf, "{:X}", u.hyphenated(), 36, |c| c.is_uppercase()
-            || c.is_digit(10)
-            || c == '-');
-        check!(buf, "{:X}", u.simple(), 32, |c| c.is_uppercase()
-            || c.is_digit(10));
-        check!(buf, "{:#X}", u.hyphenated(), 36, |c| c.is_uppercase()
-            || c.is_digit(10)
-            || c == '-');
-        check!(buf, "{:#X}", u.simple(), 32, |c| c.is_uppercase()
-            || c.is_digit(10));
+        let mut folded: Vec<u64> = (0..10_000)
+            .map(|_| Uuid::from_bytes(random_bytes()).fold_u64())
+            .collect();
+        folded.sort_unstable();
+        folded.dedup();
 
-        check!(buf, "{:x}", u.hyphenated(), 36, |c| c.is_lowercase()
-            || c.is_digit(10)
-            || c == '-');
-        check!(buf, "{:x}", u.simple(), 32, |c| c.is_lowercase()
-            || c.is_digit(10));
-        check!(buf, "{:#x}", u.hyphenated(), 36, |c| c.is_lowercase()
-            || c.is_digit(10)
-            || c == '-');
-        check!(buf, "{:#x}", u.simple(), 32, |c| c.is_lowercase()
-            || c.is_digit(10));
+        assert!(folded.len() >= 9_990);
     }
 
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
-    fn test_to_urn_string() {
-        let uuid1 = new();
-        let ss = uuid1.urn().to_string();
-        let s = &ss[9..];
+    fn test_high_low_u64() {
+        let uuid = new();
+        let (high, low) = uuid.as_u64_pair();
 
-        assert!(ss.starts_with("urn:uuid:"));
-        assert_eq!(s.len(), 36);
-        assert!(s.chars().all(|c| c.is_digit(16) || c == '-'));
+        assert_eq!(uuid.high_u64(), high);
+        assert_eq!(uuid.low_u64(), low);
+        assert_eq!(Uuid::from_u64_pair(uuid.high_u64(), uuid.low_u64()), uuid);
     }
 
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
-    fn test_to_simple_string_matching() {
-        let uuid1 = new();
+    fn test_looks_sequential_with() {
+        let base = Uuid::from_u64_pair(0x0123_4567_89ab_cdef, 0x8000_0000_0000_1000);
 
-        let hs = uuid1.hyphenated().to_string();
-        let ss = uuid1.simple().to_string();
+        for step in [1, 2, 1000, 1 << 16] {
+            let next = Uuid::from_u64_pair(base.high_u64(), base.low_u64() + step);
 
-        let hsn = hs.chars().filter(|&c| c != '-').collect::<String>();
+            assert!(base.looks_sequential_with(&next));
+            assert!(next.looks_sequential_with(&base));
+        }
 
-        assert_eq!(hsn, ss);
+        let far = Uuid::from_u64_pair(base.high_u64(), base.low_u64() + (1 << 16) + 1);
+        let other_high = Uuid::from_u64_pair(base.high_u64() + 1, base.low_u64() + 1);
+
+        assert!(!base.looks_sequential_with(&base));
+        assert!(!base.looks_sequential_with(&far));
+        assert!(!base.looks_sequential_with(&other_high));
     }
 
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
-    fn test_string_roundtrip() {
-        let uuid = new();
+    fn test_looks_sequential_with_random() {
+        let mut random_bytes = xorshift(0x5eed);
 
-        let hs = uuid.hyphenated().to_string();
-        let uuid_hs = Uuid::parse_str(&hs).unwrap();
-        assert_eq!(uuid_hs, uuid);
+        let mut prev = Uuid::from_bytes(random_bytes());
 
-        let ss = uuid.to_string();
-        let uuid_ss = Uuid::parse_str(&ss).unwrap();
-        assert_eq!(uuid_ss, uuid);
+        for _ in 0..1_000 {
+            let next = Uuid::from_bytes(random_bytes());
+
+            assert!(!prev.looks_sequential_with(&next));
+
+            prev = next;
+        }
     }
 
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
-    fn test_from_fields() {
-        let d1: u32 = 0xa1a2a3a4;
-        let d2: u16 = 0xb1b2;
-        let d3: u16 = 0xc1c2;
-        let d4 = [0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8];
-
-        let u = Uuid::from_fields(d1, d2, d3, &d4);
-
-        let expected = "a1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8";
-        let result = u.simple().to_string();
-        assert_eq!(result, expected);
+    fn test_check_char() {
+        assert_eq!(Uuid::nil().check_char(), '0');
+        assert_eq!(Uuid::from_u128(u128::MAX).check_char(), 'G');
+        assert_eq!(Uuid::from_u128(0x1).check_char(), 'Y');
+        assert_eq!(Uuid::from_u128(0x10).check_char(), 'Z');
     }
 
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
-    fn test_from_fields_le() {
-        let d1: u32 = 0xa4a3a2a1;
-        let d2: u16 = 0xb2b1;
-        let d3: u16 = 0xc2c1;
+    fn test_from_fields_endian() {
+        let d1: u32 = 0xa1a2a3a4;
+        let d2: u16 = 0xb1b2;
+        let d3: u16 = 0xc1c2;
         let d4 = [0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8];
 
-        let u = Uuid::from_fields_le(d1, d2, d3, &d4);
-
-        let expected = "a1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8";
-        let result = u.simple().to_string();
-        assert_eq!(result, expected);
+        assert_eq!(
+            Uuid::from_fields(d1, d2, d3, &d4),
+            Uuid::from_fields_endian(d1, d2, d3, &d4, Endianness::Big)
+        );
+        assert_eq!(
+            Uuid::from_fields_le(d1, d2, d3, &d4),
+            Uuid::from_fields_endian(d1, d2, d3, &d4, Endianness::Little)
+        );
     }
 
     #[test]
@@ -1405,6 +3183,142 @@ mod tests {
         assert_eq!(d4_in, d4_out);
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_with_node_id() {
+        let uuid = Uuid::from_u128(0x6ba7b810_9dad_11d1_80b4_00c04fd430c8);
+        let (ticks, counter) = timestamp::decode_rfc4122_timestamp(&uuid);
+
+        let anonymized = uuid.with_node_id([0xff; 6]);
+
+        assert_eq!(anonymized.node(), [0xff; 6]);
+        assert_eq!(anonymized.get_version(), Some(Version::Mac));
+        assert_eq!(anonymized.get_variant(), Variant::RFC4122);
+        assert_eq!(
+            timestamp::decode_rfc4122_timestamp(&anonymized),
+            (ticks, counter)
+        );
+        assert_eq!(anonymized.as_bytes()[..10], uuid.as_bytes()[..10]);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_with_clock_seq() {
+        let uuid = Uuid::from_u128(0x6ba7b810_9dad_11d1_80b4_00c04fd430c8);
+        let (ticks, _) = timestamp::decode_rfc4122_timestamp(&uuid);
+
+        for &clock_seq in &[0, 1, 0x1234, 0x3fff] {
+            let updated = uuid.with_clock_seq(clock_seq);
+
+            assert_eq!(updated.clock_seq() & 0x3fff, clock_seq);
+            assert_eq!(updated.get_version(), Some(Version::Mac));
+            assert_eq!(updated.get_variant(), Variant::RFC4122);
+            assert_eq!(
+                timestamp::decode_rfc4122_timestamp(&updated),
+                (ticks, clock_seq)
+            );
+            assert_eq!(updated.node(), uuid.node());
+        }
+
+        // The bits reserved for the variant are ignored
+        assert_eq!(uuid.with_clock_seq(0xffff), uuid.with_clock_seq(0x3fff));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_truncate_low_bits() {
+        let uuid = new();
+
+        assert_eq!(uuid.truncate_low_bits(0), uuid);
+        assert_eq!(
+            uuid.truncate_low_bits(64),
+            Uuid::from_u64_pair(uuid.high_u64(), 0)
+        );
+        assert_eq!(uuid.truncate_low_bits(128), Uuid::nil());
+        assert_eq!(
+            Uuid::from_u128(u128::MAX).truncate_low_bits(127).as_u128(),
+            1 << 127
+        );
+
+        // UUIDs that only differ in the cleared bits end up in the same bucket
+        assert_eq!(
+            Uuid::from_u128(0x1234 << 64 | 0xff).truncate_low_bits(64),
+            Uuid::from_u128(0x1234 << 64 | 0xab).truncate_low_bits(64)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_truncate_low_bits_too_many() {
+        let _ = new().truncate_low_bits(129);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_crc16() {
+        fn crc16_bitwise(bytes: &[u8]) -> u16 {
+            let mut crc: u16 = 0xffff;
+
+            for &b in bytes {
+                crc ^= (b as u16) << 8;
+
+                for _ in 0..8 {
+                    crc = if crc & 0x8000 != 0 {
+                        crc << 1 ^ 0x1021
+                    } else {
+                        crc << 1
+                    };
+                }
+            }
+
+            crc
+        }
+
+        // The standard check value of CRC-16/CCITT-FALSE
+        assert_eq!(crc16_bitwise(b"123456789"), 0x29b1);
+
+        let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        assert_eq!(uuid.crc16(), 0xc95f);
+
+        assert_eq!(Uuid::nil().crc16(), crc16_bitwise(&[0; 16]));
+        assert_eq!(new().crc16(), crc16_bitwise(new().as_bytes()));
+
+        let mut random_bytes = xorshift(0xc7c);
+
+        for _ in 0..1_000 {
+            let uuid = Uuid::from_bytes(random_bytes());
+
+            assert_eq!(uuid.crc16(), crc16_bitwise(uuid.as_bytes()));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_field_accessors() {
+        // A version 1 UUID from RFC4122
+        const UUID: Uuid = Uuid::from_u128(0x6ba7b810_9dad_11d1_80b4_00c04fd430c8);
+
+        const TIME_LOW: u32 = UUID.time_low();
+        const NODE: [u8; 6] = UUID.node();
+
+        assert_eq!(TIME_LOW, 0x6ba7b810);
+        assert_eq!(UUID.time_mid(), 0x9dad);
+        assert_eq!(UUID.time_hi_and_version(), 0x11d1);
+        assert_eq!(UUID.time_hi_and_version() >> 12, 1);
+        assert_eq!(UUID.clock_seq(), 0x80b4);
+        assert_eq!(UUID.clock_seq() & 0x3fff, 0x00b4);
+        assert_eq!(NODE, [0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8]);
+
+        let u = new();
+        let (d1, d2, d3, d4) = u.as_fields();
+
+        assert_eq!(u.time_low(), d1);
+        assert_eq!(u.time_mid(), d2);
+        assert_eq!(u.time_hi_and_version(), d3);
+        assert_eq!(u.clock_seq().to_be_bytes(), d4[..2]);
+        assert_eq!(u.node(), d4[2..]);
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn test_from_u128() {
@@ -1464,6 +3378,138 @@ mod tests {
         assert_eq!(v_in, v_out);
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_to_be_le_u128() {
+        let mut random_bytes = xorshift(0xbe1e);
+
+        for u in [new(), new2(), Uuid::nil(), Uuid::from_bytes([0xff; 16])]
+            .iter()
+            .copied()
+            .chain((0..1000).map(|_| Uuid::from_bytes(random_bytes())))
+        {
+            assert_eq!(u.as_u128(), u.to_be_u128());
+            assert_eq!(u.to_u128_le(), u.to_le_u128());
+            assert_eq!(u.to_be_u128().swap_bytes(), u.to_le_u128());
+
+            assert_eq!(Uuid::from_u128(u.to_be_u128()), u);
+            assert_eq!(Uuid::from_u128_le(u.to_le_u128()), u);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(feature = "std")]
+    fn test_hash_into() {
+        use std::{
+            collections::hash_map::DefaultHasher,
+            hash::{Hash, Hasher},
+        };
+
+        let uuid = new();
+
+        let mut trait_hasher = DefaultHasher::new();
+        uuid.hash(&mut trait_hasher);
+
+        let mut direct_hasher = DefaultHasher::new();
+        uuid.hash_into(&mut direct_hasher);
+
+        let mut u128_hasher = DefaultHasher::new();
+        u128_hasher.write_u128(uuid.as_u128());
+
+        assert_eq!(trait_hasher.finish(), direct_hasher.finish());
+        assert_eq!(trait_hasher.finish(), u128_hasher.finish());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_hash_matches_eq() {
+        use std::{
+            collections::{hash_map::DefaultHasher, HashSet},
+            hash::{Hash, Hasher},
+        };
+
+        fn hash(uuid: &Uuid) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            uuid.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let uuids = [
+            Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap(),
+            Uuid::parse_str("67E5504410B1426F9247BB680E5FE0C8").unwrap(),
+            Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8),
+            Uuid::from_u128_le(0xc8e05f0e68bb47926f42b1104450e567),
+            new(),
+        ];
+
+        for uuid in &uuids[1..4] {
+            assert_eq!(uuids[0], *uuid);
+            assert_eq!(hash(&uuids[0]), hash(uuid));
+        }
+
+        assert_ne!(hash(&uuids[0]), hash(&uuids[4]));
+
+        let set: HashSet<Uuid> = uuids.iter().copied().collect();
+        assert_eq!(2, set.len());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_range() {
+        use crate::std::vec::Vec;
+
+        let start = Uuid::from_u64_pair(1, u64::MAX - 1);
+        let end = Uuid::from_u64_pair(2, 2);
+
+        let uuids: Vec<Uuid> = Uuid::range(start, end).collect();
+
+        assert_eq!(
+            uuids,
+            [
+                Uuid::from_u64_pair(1, u64::MAX - 1),
+                Uuid::from_u64_pair(1, u64::MAX),
+                Uuid::from_u64_pair(2, 0),
+                Uuid::from_u64_pair(2, 1),
+            ]
+        );
+
+        let reversed: Vec<Uuid> = Uuid::range(start, end).rev().collect();
+        assert_eq!(reversed.first(), uuids.last());
+
+        let max = Uuid::from_u128(u128::MAX);
+        assert_eq!(Uuid::range(Uuid::nil(), max).take(2).count(), 2);
+        assert_eq!(Uuid::range(Uuid::from_u128(u128::MAX - 1), max).count(), 1);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_range_empty() {
+        let uuid = new();
+
+        assert_eq!(Uuid::range(uuid, uuid).next(), None);
+        assert_eq!(Uuid::range(uuid, Uuid::nil()).next(), None);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_u128_ne() {
+        let u = new();
+        let v = u.to_u128_ne();
+
+        #[cfg(target_endian = "big")]
+        assert_eq!(v, u.as_u128());
+
+        #[cfg(target_endian = "little")]
+        assert_eq!(v, u.to_u128_le());
+
+        assert_eq!(&v.to_ne_bytes(), u.as_bytes());
+        assert_eq!(Uuid::from_u128_ne(v), u);
+
+        let v_in: u128 = 0xa1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8;
+        assert_eq!(Uuid::from_u128_ne(v_in).to_u128_ne(), v_in);
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn test_u64_pair_roundtrip() {
@@ -1516,6 +3562,19 @@ mod tests {
         assert_eq!(u.simple().to_string(), expected);
     }
 
+    // A small xorshift generator, so random tests are reproducible
+    pub fn xorshift(seed: u128) -> impl FnMut() -> Bytes {
+        let mut state = seed;
+
+        move || {
+            state ^= state << 35;
+            state ^= state >> 59;
+            state ^= state << 11;
+
+            state.to_be_bytes()
+        }
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn test_as_bytes() {
@@ -1561,13 +3620,107 @@ mod tests {
         assert_eq!(u1, u2);
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_bytes_le_matches_fields() {
+        // A GUID as it's laid out in memory on Windows
+        const GUID: Bytes = [
+            0xa4, 0xa3, 0xa2, 0xa1, 0xb2, 0xb1, 0xc2, 0xc1, 0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6,
+            0xd7, 0xd8,
+        ];
+        const UUID: Uuid = Uuid::from_bytes_le(GUID);
+
+        assert_eq!(
+            UUID,
+            Uuid::from_fields(
+                0xa1a2a3a4,
+                0xb1b2,
+                0xc1c2,
+                &[0xd1, 0xd2, 0xd3, 0xd4, 0xd5, 0xd6, 0xd7, 0xd8]
+            )
+        );
+        assert_eq!(
+            UUID.hyphenated().to_string(),
+            "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8"
+        );
+        assert_eq!(UUID.to_bytes_le(), GUID);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_bytes_ne_roundtrip() {
+        let u1 = new();
+
+        let b_ne = u1.to_ne_bytes();
+
+        let u2 = Uuid::from_ne_bytes(b_ne);
+
+        assert_eq!(u1, u2);
+        assert_eq!(b_ne, u1.as_u128().to_ne_bytes());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_bytes_ne_are_actually_ne() {
+        let u = new();
+
+        #[cfg(target_endian = "big")]
+        assert_eq!(&u.to_ne_bytes(), u.as_bytes());
+
+        #[cfg(target_endian = "little")]
+        {
+            let mut reversed = *u.as_bytes();
+            reversed.reverse();
+
+            assert_eq!(u.to_ne_bytes(), reversed);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_u32_array_ne() {
+        let u = new();
+
+        let words = u.as_u32_array_ne();
+
+        #[cfg(target_endian = "big")]
+        assert_eq!(words, [0xF9168C5E, 0xCEB24FAA, 0xB6BF329B, 0xF39FA1E4]);
+
+        #[cfg(target_endian = "little")]
+        assert_eq!(words, [0x5E8C16F9, 0xAA4FB2CE, 0x9B32BFB6, 0xE4A19FF3]);
+
+        assert_eq!(Uuid::from_u32_array_ne(words), u);
+
+        for (i, word) in words.iter().enumerate() {
+            assert_eq!(&word.to_ne_bytes(), &u.as_bytes()[i * 4..i * 4 + 4]);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_gpt_bytes() {
+        // The EFI System Partition type GUID, as stored in a GPT partition entry
+        let on_disk = [
+            0x28, 0x73, 0x2a, 0xc1, 0x1f, 0xf8, 0xd2, 0x11, 0xba, 0x4b, 0x00, 0xa0, 0xc9, 0x3e,
+            0xc9, 0x3b,
+        ];
+
+        let uuid = Uuid::from_gpt_bytes(on_disk);
+
+        assert_eq!(
+            uuid.hyphenated().to_string(),
+            "c12a7328-f81f-11d2-ba4b-00a0c93ec93b"
+        );
+        assert_eq!(uuid.to_gpt_bytes(), on_disk);
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn test_iterbytes_impl_for_uuid() {
         let mut set = std::collections::HashSet::new();
         let id1 = new();
         let id2 = new2();
-        set.insert(id1.clone());
+        set.insert(id1);
 
         assert!(set.contains(&id1));
         assert!(!set.contains(&id2));
@@ -15,7 +15,8 @@
 
 use crate::{
     error::*,
-    std::{convert::TryFrom, str},
+    fmt::Format,
+    std::{convert::TryFrom, hash, str},
     Uuid,
 };
 
@@ -35,7 +36,219 @@ impl TryFrom<&'_ str> for Uuid {
     }
 }
 
+/// The string formats accepted when parsing a [`Uuid`] with
+/// [`Uuid::parse_with`].
+///
+/// Hex digits may be upper or lower-case unless a mode says otherwise.
+/// Prefixes and separators like `urn:uuid:`, braces, and hyphens always have
+/// to match exactly.
+///
+/// [`Uuid`]: struct.Uuid.html
+/// [`Uuid::parse_with`]: struct.Uuid.html#method.parse_with
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ParseMode {
+    /// Any of the formats below, plus hyphenated UUIDs with upper-case hex
+    /// digits, like `67E55044-10B1-426F-9247-BB680E5FE0C8`.
+    ///
+    /// This is the mode used by [`Uuid::parse_str`].
+    ///
+    /// [`Uuid::parse_str`]: struct.Uuid.html#method.parse_str
+    Lenient,
+    /// Only the canonical hyphenated format with lower-case hex digits, like
+    /// `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+    CanonicalOnly,
+    /// Only the hyphenated format, with hex digits in either case, like
+    /// `67E55044-10b1-426f-9247-bb680e5fe0c8`.
+    ///
+    /// This is the mode used by [`Uuid::try_parse_hyphenated`].
+    ///
+    /// [`Uuid::try_parse_hyphenated`]: struct.Uuid.html#method.try_parse_hyphenated
+    HyphenatedOnly,
+    /// Only 32 hex digits without hyphens, like
+    /// `67e5504410b1426f9247bb680e5fe0c8`.
+    SimpleOnly,
+    /// Only a hyphenated UUID wrapped in braces, like
+    /// `{67e55044-10b1-426f-9247-bb680e5fe0c8}`.
+    Braced,
+    /// Only a hyphenated UUID with a `urn:uuid:` prefix, like
+    /// `urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8`.
+    Urn,
+}
+
+/// A UUID string that's compared and hashed by the [`Uuid`] it represents.
+///
+/// Two strings are equal if they parse to the same `Uuid` with
+/// [`Uuid::try_parse`], whatever their case or format, so they can be used
+/// to deduplicate keys in a map while keeping the original strings around.
+/// Strings that aren't valid UUIDs are only equal to exactly the same
+/// string, and never to a valid one.
+///
+/// The string is parsed again each time it's compared or hashed. To avoid
+/// that, parse it into a `Uuid` once and use that as the key instead.
+///
+/// # Examples
+///
+/// ```
+/// # use std::collections::HashSet;
+/// # use uuid::CaseInsensitiveUuidStr;
+/// let mut seen = HashSet::new();
+///
+/// assert!(seen.insert(CaseInsensitiveUuidStr::new("67e55044-10b1-426f-9247-bb680e5fe0c8")));
+/// assert!(!seen.insert(CaseInsensitiveUuidStr::new("67E55044-10B1-426F-9247-BB680E5FE0C8")));
+///
+/// assert_eq!(1, seen.len());
+/// ```
+///
+/// [`Uuid`]: struct.Uuid.html
+/// [`Uuid::try_parse`]: struct.Uuid.html#method.try_parse
+#[derive(Clone, Copy, Debug)]
+pub struct CaseInsensitiveUuidStr<'a>(&'a str);
+
+impl<'a> CaseInsensitiveUuidStr<'a> {
+    /// Wraps a UUID string without parsing it.
+    pub const fn new(input: &'a str) -> Self {
+        CaseInsensitiveUuidStr(input)
+    }
+
+    /// Returns the original string.
+    pub const fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    /// Returns the `Uuid` the string represents, or `None` if it isn't a
+    /// valid UUID.
+    pub fn uuid(&self) -> Option<Uuid> {
+        Uuid::try_parse(self.0).ok()
+    }
+}
+
+impl PartialEq for CaseInsensitiveUuidStr<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.uuid(), other.uuid()) {
+            (Some(a), Some(b)) => a == b,
+            (None, None) => self.0 == other.0,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for CaseInsensitiveUuidStr<'_> {}
+
+impl hash::Hash for CaseInsensitiveUuidStr<'_> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        match self.uuid() {
+            Some(uuid) => {
+                state.write_u8(1);
+                uuid.hash_into(state);
+            }
+            None => {
+                state.write_u8(0);
+                self.0.hash(state);
+            }
+        }
+    }
+}
+
 impl Uuid {
+    /// Parses a `Uuid` from a string in the format selected by `mode`.
+    ///
+    /// See [`ParseMode`] for the formats each mode accepts. Since the mode is
+    /// a plain value it can be picked at runtime, such as from configuration.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `input` isn't a valid UUID in
+    /// any format, with the same details as [`parse_str`]. If `input` is a
+    /// valid UUID but in a format that `mode` doesn't accept then the error
+    /// will say so.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::{ParseMode, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_with("{67e55044-10b1-426f-9247-bb680e5fe0c8}", ParseMode::Braced)?;
+    ///
+    /// assert_eq!(
+    ///     uuid,
+    ///     Uuid::parse_with("67e55044-10b1-426f-9247-bb680e5fe0c8", ParseMode::Lenient)?,
+    /// );
+    ///
+    /// assert!(Uuid::parse_with("67e55044-10b1-426f-9247-bb680e5fe0c8", ParseMode::Urn).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ParseMode`]: enum.ParseMode.html
+    /// [`parse_str`]: #method.parse_str
+    pub fn parse_with(input: &str, mode: ParseMode) -> Result<Uuid, Error> {
+        let (uuid, format) = Uuid::parse_str_detect(input)?;
+
+        let accepted = match mode {
+            ParseMode::Lenient => true,
+            ParseMode::CanonicalOnly => Uuid::is_canonical_str(input),
+            ParseMode::HyphenatedOnly => format == Format::Hyphenated,
+            ParseMode::SimpleOnly => format == Format::Simple,
+            ParseMode::Braced => format == Format::Braced,
+            ParseMode::Urn => format == Format::Urn,
+        };
+
+        if accepted {
+            Ok(uuid)
+        } else {
+            Err(Error(ErrorKind::Mode { mode }))
+        }
+    }
+
+    /// Parses a `Uuid` from a string in any supported format, and returns
+    /// the format it was in.
+    ///
+    /// This accepts the same input as [`parse_str`]. Hex digits may be in
+    /// either case, so the returned [`Format`] only describes the shape of
+    /// the input. This can be used to find out which formats are in use
+    /// before normalizing them.
+    ///
+    /// # Errors
+    ///
+    /// This function will return the same errors as [`parse_str`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::{fmt::Format, Uuid};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let (uuid, format) = Uuid::parse_str_detect("{67e55044-10b1-426f-9247-bb680e5fe0c8}")?;
+    ///
+    /// assert_eq!(Format::Braced, format);
+    /// assert_eq!("67e55044-10b1-426f-9247-bb680e5fe0c8", uuid.to_string());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`parse_str`]: #method.parse_str
+    /// [`Format`]: fmt/enum.Format.html
+    pub fn parse_str_detect(input: &str) -> Result<(Uuid, Format), Error> {
+        let uuid = try_parse(input.as_bytes())
+            .map(Uuid::from_bytes)
+            .map_err(InvalidUuid::into_err)?;
+
+        // The input is already known to be a valid UUID, so its
+        // length is enough to tell which format it's in
+        let format = match input.len() {
+            32 => Format::Simple,
+            36 => Format::Hyphenated,
+            38 => Format::Braced,
+            _ => Format::Urn,
+        };
+
+        Ok((uuid, format))
+    }
+
     /// Parses a `Uuid` from a string of hexadecimal digits with optional
     /// hyphens.
     ///
@@ -45,6 +258,8 @@ impl Uuid {
     /// Prefer [`try_parse`] unless you need detailed user-facing diagnostics.
     /// This method will be eventually deprecated in favor of `try_parse`.
     ///
+    /// This is the same as [`parse_with`] using [`ParseMode::Lenient`].
+    ///
     /// # Examples
     ///
     /// Parse a hyphenated UUID:
@@ -61,10 +276,52 @@ impl Uuid {
     /// ```
     ///
     /// [`try_parse`]: #method.try_parse
+    /// [`parse_with`]: #method.parse_with
+    /// [`ParseMode::Lenient`]: enum.ParseMode.html#variant.Lenient
     pub fn parse_str(input: &str) -> Result<Uuid, Error> {
-        try_parse(input.as_bytes())
-            .map(Uuid::from_bytes)
-            .map_err(InvalidUuid::into_err)
+        Uuid::parse_with(input, ParseMode::Lenient)
+    }
+
+    /// Parses a `Uuid` from a hyphenated string, rejecting every other
+    /// format.
+    ///
+    /// Only the 8-4-4-4-12 layout is accepted, with hex digits in either
+    /// case. Simple, braced, and URN strings are rejected even though
+    /// [`parse_str`] would accept them, so this can be used to check input
+    /// is in a single predictable format before it's stored.
+    ///
+    /// This is the same as [`parse_with`] using [`ParseMode::HyphenatedOnly`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `input` isn't a hyphenated
+    /// UUID. Invalid characters, groups of the wrong length, and missing
+    /// hyphens are reported the same way as by [`parse_str`]. A valid UUID
+    /// in some other format gets an error saying the hyphenated format was
+    /// expected.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::try_parse_hyphenated("67E55044-10B1-426F-9247-BB680E5FE0C8")?;
+    ///
+    /// assert_eq!("67e55044-10b1-426f-9247-bb680e5fe0c8", uuid.to_string());
+    ///
+    /// assert!(Uuid::try_parse_hyphenated("67e5504410b1426f9247bb680e5fe0c8").is_err());
+    /// assert!(Uuid::try_parse_hyphenated("{67e55044-10b1-426f-9247-bb680e5fe0c8}").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`parse_str`]: #method.parse_str
+    /// [`parse_with`]: #method.parse_with
+    /// [`ParseMode::HyphenatedOnly`]: enum.ParseMode.html#variant.HyphenatedOnly
+    pub fn try_parse_hyphenated(input: &str) -> Result<Uuid, Error> {
+        Uuid::parse_with(input, ParseMode::HyphenatedOnly)
     }
 
     /// Parses a `Uuid` from a string of hexadecimal digits with optional
@@ -130,153 +387,667 @@ impl Uuid {
             Err(_) => Err(Error(ErrorKind::Other)),
         }
     }
-}
 
-const fn try_parse(input: &[u8]) -> Result<[u8; 16], InvalidUuid> {
-    let result = match (input.len(), input) {
-        // Inputs of 32 bytes must be a non-hyphenated UUID
-        (32, s) => parse_simple(s),
-        // Hyphenated UUIDs may be wrapped in various ways:
-        // - `{UUID}` for braced UUIDs
-        // - `urn:uuid:UUID` for URNs
-        // - `UUID` for a regular hyphenated UUID
-        (36, s)
-        | (38, [b'{', s @ .., b'}'])
-        | (45, [b'u', b'r', b'n', b':', b'u', b'u', b'i', b'd', b':', s @ ..]) => {
-            parse_hyphenated(s)
+    /// Parses a `Uuid` from a string, treating empty input as the nil UUID.
+    ///
+    /// If `input` is empty or only contains whitespace then this method
+    /// returns [`Uuid::nil`] instead of an error. This matches configuration
+    /// formats where an empty field means "unset". Any other input is parsed
+    /// with [`parse_str`], so it must be a valid UUID without surrounding
+    /// whitespace.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `input` isn't empty or blank,
+    /// and isn't a valid UUID.
+    ///
+    /// # Examples
+    ///
+    /// Parse an empty field:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// assert_eq!(Uuid::nil(), Uuid::parse_str_or_nil("")?);
+    /// assert_eq!(Uuid::nil(), Uuid::parse_str_or_nil("  ")?);
+    ///
+    /// let uuid = Uuid::parse_str_or_nil("550e8400-e29b-41d4-a716-446655440000")?;
+    /// assert!(!uuid.is_nil());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Uuid::nil`]: #method.nil
+    /// [`parse_str`]: #method.parse_str
+    pub fn parse_str_or_nil(input: &str) -> Result<Uuid, Error> {
+        if input.trim().is_empty() {
+            Ok(Uuid::nil())
+        } else {
+            Uuid::parse_str(input)
         }
-        // Any other shaped input is immediately invalid
-        _ => Err(()),
-    };
-
-    match result {
-        Ok(b) => Ok(b),
-        Err(()) => Err(InvalidUuid(input)),
     }
-}
-
-#[inline]
-const fn parse_simple(s: &[u8]) -> Result<[u8; 16], ()> {
-    // This length check here removes all other bounds
-    // checks in this function
-    if s.len() != 32 {
-        return Err(());
-    }
-
-    let mut buf: [u8; 16] = [0; 16];
-    let mut i = 0;
 
-    while i < 16 {
-        // Convert a two-char hex value (like `A8`)
-        // into a byte (like `10101000`)
-        let h1 = HEX_TABLE[s[i * 2] as usize];
-        let h2 = HEX_TABLE[s[i * 2 + 1] as usize];
+    /// Checks whether a string is a UUID in its canonical form.
+    ///
+    /// The canonical form is the lower-case hyphenated form, like
+    /// `67e55044-10b1-426f-9247-bb680e5fe0c8`, which is what the
+    /// [`fmt::Display`] implementation of `Uuid` produces. Strings in other
+    /// forms, or that use upper-case hex digits, may still be accepted by
+    /// [`parse_str`] but aren't canonical.
+    ///
+    /// This method doesn't allocate.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// assert!(Uuid::is_canonical_str("67e55044-10b1-426f-9247-bb680e5fe0c8"));
+    ///
+    /// assert!(!Uuid::is_canonical_str("67E55044-10B1-426F-9247-BB680E5FE0C8"));
+    /// assert!(!Uuid::is_canonical_str("67e5504410b1426f9247bb680e5fe0c8"));
+    /// ```
+    ///
+    /// [`fmt::Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+    /// [`parse_str`]: #method.parse_str
+    pub const fn is_canonical_str(input: &str) -> bool {
+        let s = input.as_bytes();
 
-        // We use `0xff` as a sentinel value to indicate
-        // an invalid hex character sequence (like the letter `G`)
-        if h1 | h2 == 0xff {
-            return Err(());
+        if s.len() != 36 {
+            return false;
         }
 
-        // The upper nibble needs to be shifted into position
-        // to produce the final byte value
-        buf[i] = SHL4_TABLE[h1 as usize] | h2;
-        i += 1;
-    }
-
-    Ok(buf)
-}
-
-#[inline]
-const fn parse_hyphenated(s: &[u8]) -> Result<[u8; 16], ()> {
-    // This length check here removes all other bounds
-    // checks in this function
-    if s.len() != 36 {
-        return Err(());
-    }
-
-    // We look at two hex-encoded values (4 chars) at a time because
-    // that's the size of the smallest group in a hyphenated UUID.
-    // The indexes we're interested in are:
-    //
-    // uuid     : 936da01f-9abd-4d9d-80c7-02af85c822a8
-    //            |   |   ||   ||   ||   ||   |   |
-    // hyphens  : |   |   8|  13|  18|  23|   |   |
-    // positions: 0   4    9   14   19   24  28  32
-
-    // First, ensure the hyphens appear in the right places
-    match [s[8], s[13], s[18], s[23]] {
-        [b'-', b'-', b'-', b'-'] => {}
-        _ => return Err(()),
-    }
-
-    let positions: [u8; 8] = [0, 4, 9, 14, 19, 24, 28, 32];
-    let mut buf: [u8; 16] = [0; 16];
-    let mut j = 0;
-
-    while j < 8 {
-        let i = positions[j];
+        let mut i = 0;
+        while i < s.len() {
+            let valid = match i {
+                8 | 13 | 18 | 23 => s[i] == b'-',
+                _ => matches!(s[i], b'0'..=b'9' | b'a'..=b'f'),
+            };
 
-        // The decoding here is the same as the simple case
-        // We're just dealing with two values instead of one
-        let h1 = HEX_TABLE[s[i as usize] as usize];
-        let h2 = HEX_TABLE[s[(i + 1) as usize] as usize];
-        let h3 = HEX_TABLE[s[(i + 2) as usize] as usize];
-        let h4 = HEX_TABLE[s[(i + 3) as usize] as usize];
+            if !valid {
+                return false;
+            }
 
-        if h1 | h2 | h3 | h4 == 0xff {
-            return Err(());
+            i += 1;
         }
 
-        buf[j * 2] = SHL4_TABLE[h1 as usize] | h2;
-        buf[j * 2 + 1] = SHL4_TABLE[h3 as usize] | h4;
-        j += 1;
+        true
     }
 
-    Ok(buf)
-}
-
-const HEX_TABLE: &[u8; 256] = &{
-    let mut buf = [0; 256];
-    let mut i: u8 = 0;
+    /// Parses a `Uuid` from a string followed by its check character.
+    ///
+    /// The last character of the input is stripped and compared against the
+    /// [`check_char`] of the UUID parsed from the rest of the input, ignoring
+    /// case. Any format supported by [`parse_str`] can be used for the UUID.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the UUID can't be parsed, or if
+    /// the check character doesn't match.
+    ///
+    /// # Examples
+    ///
+    /// Parse a UUID with a check character:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::from_checked_str("67e55044-10b1-426f-9247-bb680e5fe0c8A")?;
+    ///
+    /// assert_eq!(
+    ///     "67e55044-10b1-426f-9247-bb680e5fe0c8",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    ///
+    /// assert!(Uuid::from_checked_str("67e55044-10b1-426f-9247-bb680e5fe0d8A").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`check_char`]: #method.check_char
+    /// [`parse_str`]: #method.parse_str
+    pub fn from_checked_str(input: &str) -> Result<Uuid, Error> {
+        let mut chars = input.chars();
 
-    loop {
-        buf[i as usize] = match i {
-            b'0'..=b'9' => i - b'0',
-            b'a'..=b'f' => i - b'a' + 10,
-            b'A'..=b'F' => i - b'A' + 10,
-            _ => 0xff,
+        let character = match chars.next_back() {
+            Some(character) => character,
+            None => return Err(Error(ErrorKind::SimpleLength { len: 0 })),
         };
 
-        if i == 255 {
-            break buf;
-        }
+        let uuid = Uuid::parse_str(chars.as_str())?;
 
-        i += 1
+        if character.to_ascii_uppercase() == uuid.check_char() {
+            Ok(uuid)
+        } else {
+            Err(Error(ErrorKind::CheckChar { character }))
+        }
     }
-};
-
-const SHL4_TABLE: &[u8; 256] = &{
-    let mut buf = [0; 256];
-    let mut i: u8 = 0;
 
-    loop {
-        buf[i as usize] = i.wrapping_shl(4);
+    /// Parses a `Uuid` from a hex dump of its bytes.
+    ///
+    /// Hex editors and tools like `xxd` often render bytes as space-separated
+    /// pairs of hexadecimal digits. This method ignores any ASCII whitespace
+    /// in the input (spaces, tabs, and newlines) and parses the remaining
+    /// digits as a simple UUID.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the input contains any character
+    /// that isn't a hexadecimal digit or ASCII whitespace, or if there aren't
+    /// exactly 32 hexadecimal digits.
+    ///
+    /// # Examples
+    ///
+    /// Parse a UUID from a dump of its bytes:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::from_hex_dump("67 e5 50 44 10 b1 42 6f 92 47 bb 68 0e 5f e0 c8")?;
+    ///
+    /// assert_eq!(
+    ///     "67e55044-10b1-426f-9247-bb680e5fe0c8",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_hex_dump(input: &str) -> Result<Uuid, Error> {
+        let mut buf = [0; 32];
+        let mut len = 0;
+
+        for (index, character) in input.char_indices() {
+            if character.is_ascii_whitespace() {
+                continue;
+            }
+
+            if !character.is_ascii_hexdigit() {
+                return Err(Error(ErrorKind::Char {
+                    character,
+                    index: index + 1,
+                }));
+            }
+
+            // Keep counting past the end of the buffer so the
+            // error can report the total number of digits
+            if len < buf.len() {
+                buf[len] = character as u8;
+            }
+            len += 1;
+        }
 
-        if i == 255 {
-            break buf;
+        if len != buf.len() {
+            return Err(Error(ErrorKind::SimpleLength { len }));
         }
 
-        i += 1;
+        parse_simple(&buf)
+            .map(Uuid::from_bytes)
+            .map_err(|()| Error(ErrorKind::Other))
     }
-};
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::{std::string::ToString, tests::new};
 
-    #[test]
+    /// Parses a `Uuid` from either a ULID or a hyphenated UUID string.
+    ///
+    /// A ULID is a 128bit value written as 26 characters of Crockford's
+    /// Base32, with the most significant bits first. Its bytes are
+    /// interpreted directly as a `Uuid`, so a ULID and a hyphenated UUID
+    /// holding the same value will parse to the same `Uuid`. This can be
+    /// used to accept both while moving from ULIDs to UUIDs.
+    ///
+    /// ULID characters may be in either case, and the letters `I`, `L`, and
+    /// `O` are read as `1`, `1`, and `0` like Crockford's Base32 specifies.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the input isn't 26 or 36
+    /// characters long, if a ULID contains a character outside of the
+    /// alphabet or is larger than 128 bits, or if a 36 character input
+    /// isn't a valid hyphenated UUID.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let ulid = Uuid::from_ulid_or_uuid("01ARZ3NDEKTSV4RRFFQ69G5FAV")?;
+    /// let uuid = Uuid::from_ulid_or_uuid("01563e3a-b5d3-d676-4c61-efb99302bd5b")?;
+    ///
+    /// assert_eq!(ulid, uuid);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [ULID specification](https://github.com/ulid/spec)
+    /// * [Crockford's Base32](https://www.crockford.com/base32.html)
+    pub fn from_ulid_or_uuid(input: &str) -> Result<Uuid, Error> {
+        match input.len() {
+            26 => parse_ulid(input),
+            36 => try_parse(input.as_bytes())
+                .map(Uuid::from_bytes)
+                .map_err(InvalidUuid::into_err),
+            len => Err(Error(ErrorKind::UlidLength { len })),
+        }
+    }
+
+    /// Parses a `Uuid` from an OID URN in the `2.25` arc, like
+    /// `urn:oid:2.25.138101147531900207301164854559698313416`.
+    ///
+    /// ITU-T X.667 represents a UUID as an OID made of the arc `2.25`
+    /// followed by the UUID as a single unsigned decimal integer. This is the
+    /// OID form of the `urn:uuid:` URNs accepted by [`parse_str`]. Also see
+    /// [`to_oid_string`], which formats the OID without the `urn:oid:`
+    /// prefix.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the input doesn't start with
+    /// `urn:oid:2.25.`, if the rest of the input isn't a decimal, or if the
+    /// decimal has leading zeros or is 2^128 or more.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::from_urn_oid("urn:oid:2.25.138101147531900207301164854559698313416")?;
+    ///
+    /// assert_eq!(
+    ///     "67e55044-10b1-426f-9247-bb680e5fe0c8",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [ITU-T X.667](https://www.itu.int/rec/T-REC-X.667)
+    /// * [OIDs for UUIDs in RFC4122](https://www.rfc-editor.org/rfc/rfc4122#section-1)
+    ///
+    /// [`parse_str`]: #method.parse_str
+    /// [`to_oid_string`]: #method.to_oid_string
+    pub fn from_urn_oid(input: &str) -> Result<Uuid, Error> {
+        const PREFIX: &str = "urn:oid:2.25.";
+
+        let decimal = match input.get(..PREFIX.len()) {
+            Some(prefix) if prefix.eq_ignore_ascii_case(PREFIX) => &input[PREFIX.len()..],
+            _ => return Err(Error(ErrorKind::OidPrefix)),
+        };
+
+        let value = parse_decimal(decimal, PREFIX.len(), ErrorKind::OidValue)?;
+
+        // Arcs are written without leading zeros, and can't be empty
+        if decimal.is_empty() || (decimal.len() > 1 && decimal.starts_with('0')) {
+            return Err(Error(ErrorKind::OidValue));
+        }
+
+        Ok(Uuid::from_u128(value))
+    }
+
+    /// Parses a `Uuid` from its value as a decimal integer, like
+    /// `138101147531900207301164854559698313416`.
+    ///
+    /// Some systems that can't store hexadecimal keep UUIDs as the decimal
+    /// form of [`Uuid::as_u128`] instead, often zero-padded to a fixed width
+    /// of 39 digits. The input may have any number of leading zeros, up to a
+    /// total of 39 digits. Also see [`to_decimal_string`], which formats the
+    /// padded form.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the input is empty, contains
+    /// anything other than ASCII digits, has more than 39 digits, or is
+    /// 2^128 or more.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::from_decimal_string("138101147531900207301164854559698313416")?;
+    ///
+    /// assert_eq!(
+    ///     "67e55044-10b1-426f-9247-bb680e5fe0c8",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    ///
+    /// let padded = Uuid::from_decimal_string("000000000000000000000000000000000000042")?;
+    /// let unpadded = Uuid::from_decimal_string("42")?;
+    ///
+    /// assert_eq!(padded, unpadded);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Uuid::as_u128`]: #method.as_u128
+    /// [`to_decimal_string`]: #method.to_decimal_string
+    pub fn from_decimal_string(input: &str) -> Result<Uuid, Error> {
+        let value = parse_decimal(input, 0, ErrorKind::DecimalValue)?;
+
+        if input.is_empty() || input.len() > 39 {
+            return Err(Error(ErrorKind::DecimalValue));
+        }
+
+        Ok(Uuid::from_u128(value))
+    }
+
+    /// Parses a batch of UUID strings and rewrites each one in the canonical
+    /// lower-case hyphenated format.
+    ///
+    /// Each input is parsed with [`Uuid::try_parse`], so any format it
+    /// supports is accepted. Alongside the canonical string, each result says
+    /// whether it differs from the input, so data can be fixed and audited in
+    /// a single pass. Invalid inputs produce an error in their position
+    /// without stopping the rest of the batch.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let results = Uuid::normalize_batch(&[
+    ///     "67e55044-10b1-426f-9247-bb680e5fe0c8",
+    ///     "{67E55044-10B1-426F-9247-BB680E5FE0C8}",
+    ///     "not a uuid",
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     Ok(("67e55044-10b1-426f-9247-bb680e5fe0c8".to_owned(), false)),
+    ///     results[0],
+    /// );
+    /// assert_eq!(
+    ///     Ok(("67e55044-10b1-426f-9247-bb680e5fe0c8".to_owned(), true)),
+    ///     results[1],
+    /// );
+    /// assert!(results[2].is_err());
+    /// ```
+    ///
+    /// [`Uuid::try_parse`]: #method.try_parse
+    #[cfg(feature = "std")]
+    pub fn normalize_batch(
+        inputs: &[&str],
+    ) -> std::vec::Vec<Result<(std::string::String, bool), Error>> {
+        use crate::std::string::ToString;
+
+        inputs
+            .iter()
+            .map(|input| {
+                let canonical = Uuid::try_parse(input)?.hyphenated().to_string();
+                let changed = canonical != *input;
+
+                Ok((canonical, changed))
+            })
+            .collect()
+    }
+}
+
+/// Decode a decimal into a `u128`, returning `overflow` if it doesn't fit.
+///
+/// `offset` is the position of `decimal` in the original input, so errors
+/// can point at the right character.
+fn parse_decimal(decimal: &str, offset: usize, overflow: ErrorKind) -> Result<u128, Error> {
+    let mut value: u128 = 0;
+
+    for (index, character) in decimal.char_indices() {
+        let digit = match character.to_digit(10) {
+            Some(digit) => digit,
+            None => {
+                return Err(Error(ErrorKind::Char {
+                    character,
+                    index: offset + index + 1,
+                }))
+            }
+        };
+
+        value = match value
+            .checked_mul(10)
+            .and_then(|value| value.checked_add(digit as u128))
+        {
+            Some(value) => value,
+            None => return Err(Error(overflow)),
+        };
+    }
+
+    Ok(value)
+}
+
+fn parse_ulid(input: &str) -> Result<Uuid, Error> {
+    let mut value: u128 = 0;
+
+    for (index, character) in input.char_indices() {
+        let digit = match character.to_ascii_uppercase() {
+            c @ '0'..='9' => c as u8 - b'0',
+            'O' => 0,
+            'I' | 'L' => 1,
+            c @ 'A'..='H' => c as u8 - b'A' + 10,
+            c @ 'J'..='K' => c as u8 - b'J' + 18,
+            c @ 'M'..='N' => c as u8 - b'M' + 20,
+            c @ 'P'..='T' => c as u8 - b'P' + 22,
+            c @ 'V'..='Z' => c as u8 - b'V' + 27,
+            _ => {
+                return Err(Error(ErrorKind::Char {
+                    character,
+                    index: index + 1,
+                }))
+            }
+        };
+
+        // 26 characters hold 130 bits, so the first
+        // one can only use the lowest 3 of its bits
+        if index == 0 && digit > 7 {
+            return Err(Error(ErrorKind::Char {
+                character,
+                index: index + 1,
+            }));
+        }
+
+        value = value << 5 | digit as u128;
+    }
+
+    Ok(Uuid::from_u128(value))
+}
+
+/// Strip a `urn:uuid:` prefix, in any case, from the start of the input.
+pub(crate) const fn strip_urn_prefix(s: &[u8]) -> Option<&[u8]> {
+    match s {
+        [u1, r, n, b':', u2, u3, i, d, b':', s @ ..]
+            if u1.eq_ignore_ascii_case(&b'u')
+                && r.eq_ignore_ascii_case(&b'r')
+                && n.eq_ignore_ascii_case(&b'n')
+                && u2.eq_ignore_ascii_case(&b'u')
+                && u3.eq_ignore_ascii_case(&b'u')
+                && i.eq_ignore_ascii_case(&b'i')
+                && d.eq_ignore_ascii_case(&b'd') =>
+        {
+            Some(s)
+        }
+        _ => None,
+    }
+}
+
+const fn try_parse(input: &[u8]) -> Result<[u8; 16], InvalidUuid<'_>> {
+    let result = match (input.len(), input) {
+        // Inputs of 32 bytes must be a non-hyphenated UUID
+        (32, s) => parse_simple(s),
+        // Hyphenated UUIDs may be wrapped in various ways:
+        // - `{UUID}` for braced UUIDs
+        // - `urn:uuid:UUID` for URNs, with the prefix in any case
+        // - `UUID` for a regular hyphenated UUID
+        (36, s) | (38, [b'{', s @ .., b'}']) => parse_hyphenated(s),
+        (45, s) => match strip_urn_prefix(s) {
+            Some(s) => parse_hyphenated(s),
+            None => Err(()),
+        },
+        // Any other shaped input is immediately invalid
+        _ => Err(()),
+    };
+
+    match result {
+        Ok(b) => Ok(b),
+        Err(()) => Err(InvalidUuid(input)),
+    }
+}
+
+#[inline]
+const fn parse_simple(s: &[u8]) -> Result<[u8; 16], ()> {
+    // This length check here removes all other bounds
+    // checks in this function
+    if s.len() != 32 {
+        return Err(());
+    }
+
+    let mut buf: [u8; 16] = [0; 16];
+    let mut i = 0;
+
+    while i < 16 {
+        // Convert a two-char hex value (like `A8`)
+        // into a byte (like `10101000`)
+        let h1 = HEX_TABLE[s[i * 2] as usize];
+        let h2 = HEX_TABLE[s[i * 2 + 1] as usize];
+
+        // We use `0xff` as a sentinel value to indicate
+        // an invalid hex character sequence (like the letter `G`)
+        if h1 | h2 == 0xff {
+            return Err(());
+        }
+
+        // The upper nibble needs to be shifted into position
+        // to produce the final byte value
+        buf[i] = SHL4_TABLE[h1 as usize] | h2;
+        i += 1;
+    }
+
+    Ok(buf)
+}
+
+#[inline]
+const fn parse_hyphenated(s: &[u8]) -> Result<[u8; 16], ()> {
+    // This length check here removes all other bounds
+    // checks in this function
+    if s.len() != 36 {
+        return Err(());
+    }
+
+    // We look at two hex-encoded values (4 chars) at a time because
+    // that's the size of the smallest group in a hyphenated UUID.
+    // The indexes we're interested in are:
+    //
+    // uuid     : 936da01f-9abd-4d9d-80c7-02af85c822a8
+    //            |   |   ||   ||   ||   ||   |   |
+    // hyphens  : |   |   8|  13|  18|  23|   |   |
+    // positions: 0   4    9   14   19   24  28  32
+
+    // First, ensure the hyphens appear in the right places
+    match [s[8], s[13], s[18], s[23]] {
+        [b'-', b'-', b'-', b'-'] => {}
+        _ => return Err(()),
+    }
+
+    let positions: [u8; 8] = [0, 4, 9, 14, 19, 24, 28, 32];
+    let mut buf: [u8; 16] = [0; 16];
+    let mut j = 0;
+
+    while j < 8 {
+        let i = positions[j];
+
+        // The decoding here is the same as the simple case
+        // We're just dealing with two values instead of one
+        let h1 = HEX_TABLE[s[i as usize] as usize];
+        let h2 = HEX_TABLE[s[(i + 1) as usize] as usize];
+        let h3 = HEX_TABLE[s[(i + 2) as usize] as usize];
+        let h4 = HEX_TABLE[s[(i + 3) as usize] as usize];
+
+        if h1 | h2 | h3 | h4 == 0xff {
+            return Err(());
+        }
+
+        buf[j * 2] = SHL4_TABLE[h1 as usize] | h2;
+        buf[j * 2 + 1] = SHL4_TABLE[h3 as usize] | h4;
+        j += 1;
+    }
+
+    Ok(buf)
+}
+
+/// A lookup table for decoding hexadecimal digits.
+///
+/// Indexing the table with an ASCII byte gives the value of the hex digit it
+/// represents, so `HEX_DECODE_TABLE[b'a' as usize]` is `10`. Upper and
+/// lower-case digits are both supported. Every byte that isn't a hex digit
+/// maps to `0xff`, which is never a valid digit value, so a group of digits
+/// can be validated at once by checking whether their bitwise OR is `0xff`.
+///
+/// This is the table the [`Uuid`] parser uses, so it can be reused by other
+/// parsers that need to decode hex without building their own.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use uuid::HEX_DECODE_TABLE;
+/// let digits = [HEX_DECODE_TABLE[b'6' as usize], HEX_DECODE_TABLE[b'F' as usize]];
+///
+/// assert_eq!([6, 15], digits);
+/// assert_eq!(0xff, HEX_DECODE_TABLE[b'g' as usize]);
+/// ```
+///
+/// [`Uuid`]: struct.Uuid.html
+pub const HEX_DECODE_TABLE: [u8; 256] = {
+    let mut buf = [0; 256];
+    let mut i: u8 = 0;
+
+    loop {
+        buf[i as usize] = match i {
+            b'0'..=b'9' => i - b'0',
+            b'a'..=b'f' => i - b'a' + 10,
+            b'A'..=b'F' => i - b'A' + 10,
+            _ => 0xff,
+        };
+
+        if i == 255 {
+            break buf;
+        }
+
+        i += 1
+    }
+};
+
+const HEX_TABLE: &[u8; 256] = &HEX_DECODE_TABLE;
+
+const SHL4_TABLE: &[u8; 256] = &{
+    let mut buf = [0; 256];
+    let mut i: u8 = 0;
+
+    loop {
+        buf[i as usize] = i.wrapping_shl(4);
+
+        if i == 255 {
+            break buf;
+        }
+
+        i += 1;
+    }
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        std::string::{String, ToString},
+        tests::new,
+    };
+
+    #[test]
     fn test_parse_uuid_v4_valid() {
         let from_hyphenated = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
         let from_simple = Uuid::parse_str("67e5504410b1426f9247bb680e5fe0c8").unwrap();
@@ -329,6 +1100,8 @@ mod tests {
                 group: 4,
                 len: 13,
                 index: 25,
+                total: 37,
+                wrapper_len: 0,
             }))
         );
 
@@ -338,6 +1111,8 @@ mod tests {
                 group: 3,
                 len: 3,
                 index: 20,
+                total: 35,
+                wrapper_len: 0,
             }))
         );
 
@@ -351,22 +1126,38 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2F4faaFB6BFF329BF39FA1E4"),
-            Err(Error(ErrorKind::GroupCount { count: 2 }))
+            Err(Error(ErrorKind::GroupCount {
+                count: 2,
+                total: 36,
+                wrapper_len: 0,
+            }))
         );
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2-4faaFB6BFF329BF39FA1E4"),
-            Err(Error(ErrorKind::GroupCount { count: 3 }))
+            Err(Error(ErrorKind::GroupCount {
+                count: 3,
+                total: 36,
+                wrapper_len: 0,
+            }))
         );
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2-4faa-B6BFF329BF39FA1E4"),
-            Err(Error(ErrorKind::GroupCount { count: 4 }))
+            Err(Error(ErrorKind::GroupCount {
+                count: 4,
+                total: 36,
+                wrapper_len: 0,
+            }))
         );
 
         assert_eq!(
             Uuid::parse_str("F9168C5E-CEB2-4faa"),
-            Err(Error(ErrorKind::GroupCount { count: 3 }))
+            Err(Error(ErrorKind::GroupCount {
+                count: 3,
+                total: 18,
+                wrapper_len: 0,
+            }))
         );
 
         assert_eq!(
@@ -387,7 +1178,11 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("{F9168C5E-CEB2-4faa9B6BFF329BF39FA1E41}"),
-            Err(Error(ErrorKind::GroupCount { count: 3 }))
+            Err(Error(ErrorKind::GroupCount {
+                count: 3,
+                total: 39,
+                wrapper_len: 2,
+            }))
         );
 
         assert_eq!(
@@ -396,6 +1191,8 @@ mod tests {
                 group: 1,
                 len: 3,
                 index: 10,
+                total: 36,
+                wrapper_len: 0,
             }))
         );
 
@@ -407,6 +1204,8 @@ mod tests {
                 group: 4,
                 len: 8,
                 index: 25,
+                total: 32,
+                wrapper_len: 0,
             }))
         );
 
@@ -443,7 +1242,11 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("{00000000000000000000000000000000}"),
-            Err(Error(ErrorKind::GroupCount { count: 1 }))
+            Err(Error(ErrorKind::GroupCount {
+                count: 1,
+                total: 34,
+                wrapper_len: 2,
+            }))
         );
 
         assert_eq!(
@@ -461,7 +1264,11 @@ mod tests {
 
         assert_eq!(
             Uuid::parse_str("67e550-4105b1426f9247bb680e5fe0c"),
-            Err(Error(ErrorKind::GroupCount { count: 2 }))
+            Err(Error(ErrorKind::GroupCount {
+                count: 2,
+                total: 32,
+                wrapper_len: 0,
+            }))
         );
 
         assert_eq!(
@@ -470,25 +1277,140 @@ mod tests {
                 group: 3,
                 len: 5,
                 index: 20,
+                total: 36,
+                wrapper_len: 0,
             }))
         );
     }
 
     #[test]
-    fn test_roundtrip_default() {
-        let uuid_orig = new();
-        let orig_str = uuid_orig.to_string();
-        let uuid_out = Uuid::parse_str(&orig_str).unwrap();
-        assert_eq!(uuid_orig, uuid_out);
-    }
+    fn test_parse_found_length() {
+        use crate::std::string::ToString;
 
-    #[test]
-    fn test_roundtrip_hyphenated() {
-        let uuid_orig = new();
-        let orig_str = uuid_orig.hyphenated().to_string();
-        let uuid_out = Uuid::parse_str(&orig_str).unwrap();
-        assert_eq!(uuid_orig, uuid_out);
-    }
+        let err = Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4abcd").unwrap_err();
+        assert_eq!(Some(40), err.found_length());
+        assert_eq!(
+            "invalid group length in group 4: expected 12, found 16 (expected 36 characters, found 40)",
+            err.to_string()
+        );
+
+        let err = Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF-329BF39FA1E").unwrap_err();
+        assert_eq!(Some(35), err.found_length());
+        assert_eq!(
+            "invalid group length in group 4: expected 12, found 11 (expected 36 characters, found 35)",
+            err.to_string()
+        );
+
+        let err = Uuid::parse_str("{F9168C5E-CEB2-4faa-B6BF-329BF39FA1E}").unwrap_err();
+        assert_eq!(Some(37), err.found_length());
+        assert!(err
+            .to_string()
+            .ends_with("(expected 38 characters, found 37)"));
+
+        let err = Uuid::parse_str("urn:uuid:F9168C5E-CEB2-4faa-B6BF-329BF39FA1E45").unwrap_err();
+        assert_eq!(Some(46), err.found_length());
+        assert!(err
+            .to_string()
+            .ends_with("(expected 45 characters, found 46)"));
+
+        let err = Uuid::parse_str("F9168C5ECEB24faaB6BF329BF39FA1E").unwrap_err();
+        assert_eq!(Some(31), err.found_length());
+        assert_eq!(
+            "invalid length: expected length 32 for simple format, found 31",
+            err.to_string()
+        );
+
+        // A missing group makes the input too short
+        let err = Uuid::parse_str("F9168C5E-CEB2-4faa").unwrap_err();
+        assert_eq!(Some(18), err.found_length());
+        assert_eq!(
+            "invalid group count: expected 5, found 3 (expected 36 characters, found 18)",
+            err.to_string()
+        );
+
+        let err = Uuid::parse_str("{F9168C5E-CEB2-4faa-B6BF}").unwrap_err();
+        assert_eq!(Some(25), err.found_length());
+        assert!(err
+            .to_string()
+            .ends_with("(expected 38 characters, found 25)"));
+
+        // A misplaced hyphen doesn't change the length
+        let err = Uuid::parse_str("F9168C5E-CEB-24faa-B6BF-329BF39FA1E4").unwrap_err();
+        assert_eq!(None, err.found_length());
+        assert_eq!(
+            "invalid group length in group 1: expected 4, found 3",
+            err.to_string()
+        );
+
+        let err = Uuid::parse_str("{F9168C5E-CEB-24faa-B6BF-329BF39FA1E4}").unwrap_err();
+        assert_eq!(None, err.found_length());
+        assert_eq!(
+            "invalid group length in group 1: expected 4, found 3",
+            err.to_string()
+        );
+
+        let err = Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF-329B-39FA1E4").unwrap_err();
+        assert_eq!(None, err.found_length());
+        assert_eq!("invalid group count: expected 5, found 6", err.to_string());
+
+        assert_eq!(
+            None,
+            Uuid::parse_str("F9168C5E-CEB2-4faa-B6BF-329BF39FA1EG")
+                .unwrap_err()
+                .found_length()
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        use crate::std::str::FromStr;
+
+        let expected = Uuid::from_u128(0xf9168c5e_ceb2_4faa_b6bf_329bf39fa1e4);
+
+        for input in [
+            "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e4",
+            "F9168C5E-CEB2-4FAA-B6BF-329BF39FA1E4",
+            "f9168c5eceb24faab6bf329bf39fa1e4",
+            "F9168c5eCEB24faaB6BF329bf39FA1E4",
+        ] {
+            assert_eq!(Ok(expected), Uuid::from_str(input), "{}", input);
+            assert_eq!(Ok(expected), input.parse::<Uuid>(), "{}", input);
+        }
+
+        for input in [
+            "",
+            "f9168c5e-ceb2-4faa-b6bf-329bf39fa1e",
+            "f9168c5eceb24faab6bf329bf39fa1e4a",
+            "f9168c5e-ceb2-4faa-b6bf-329bf39fa1eg",
+            "g9168c5eceb24faab6bf329bf39fa1e4",
+        ] {
+            assert_eq!(Uuid::parse_str(input), Uuid::from_str(input), "{}", input);
+        }
+
+        assert_eq!(
+            Uuid::from_str("f9168c5e-ceb2-4faa-b6bf-329bf39fa1eg"),
+            Err(Error(ErrorKind::Char {
+                character: 'g',
+                index: 36,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_roundtrip_default() {
+        let uuid_orig = new();
+        let orig_str = uuid_orig.to_string();
+        let uuid_out = Uuid::parse_str(&orig_str).unwrap();
+        assert_eq!(uuid_orig, uuid_out);
+    }
+
+    #[test]
+    fn test_roundtrip_hyphenated() {
+        let uuid_orig = new();
+        let orig_str = uuid_orig.hyphenated().to_string();
+        let uuid_out = Uuid::parse_str(&orig_str).unwrap();
+        assert_eq!(uuid_orig, uuid_out);
+    }
 
     #[test]
     fn test_roundtrip_simple() {
@@ -514,6 +1436,856 @@ mod tests {
         assert_eq!(uuid_orig, uuid_out);
     }
 
+    #[test]
+    fn test_from_hex_dump() {
+        let expected = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+
+        let spaces = "67 e5 50 44 10 b1 42 6f 92 47 bb 68 0e 5f e0 c8";
+        let tabs = "67\te5\t50\t44\t10\tb1\t42\t6f\t92\t47\tbb\t68\t0e\t5f\te0\tc8";
+        let newlines = "67 e5 50 44 10 b1 42 6f\n92 47 bb 68 0e 5f e0 c8\n";
+
+        assert_eq!(Uuid::from_hex_dump(spaces).unwrap(), expected);
+        assert_eq!(Uuid::from_hex_dump(tabs).unwrap(), expected);
+        assert_eq!(Uuid::from_hex_dump(newlines).unwrap(), expected);
+        assert_eq!(
+            Uuid::from_hex_dump("67e5504410b1426f9247bb680e5fe0c8").unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_from_hex_dump_invalid() {
+        assert_eq!(
+            Uuid::from_hex_dump(""),
+            Err(Error(ErrorKind::SimpleLength { len: 0 }))
+        );
+
+        assert_eq!(
+            Uuid::from_hex_dump("67 e5 50 44 10 b1 42 6f 92 47 bb 68 0e 5f e0"),
+            Err(Error(ErrorKind::SimpleLength { len: 30 }))
+        );
+
+        assert_eq!(
+            Uuid::from_hex_dump("67 e5 50 44 10 b1 42 6f 92 47 bb 68 0e 5f e0 c8 00"),
+            Err(Error(ErrorKind::SimpleLength { len: 34 }))
+        );
+
+        assert_eq!(
+            Uuid::from_hex_dump("67-e5-50-44-10-b1-42-6f-92-47-bb-68-0e-5f-e0-c8"),
+            Err(Error(ErrorKind::Char {
+                character: '-',
+                index: 3,
+            }))
+        );
+
+        assert_eq!(
+            Uuid::from_hex_dump("67 e5 50 44 10 b1 42 6f 92 47 bb 68 0e 5f e0 cé"),
+            Err(Error(ErrorKind::Char {
+                character: 'é',
+                index: 47,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_from_ulid_or_uuid() {
+        let expected = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+
+        assert_eq!(
+            Uuid::from_ulid_or_uuid("37WN84845H89QS4HXVD075ZR68").unwrap(),
+            expected
+        );
+        assert_eq!(
+            Uuid::from_ulid_or_uuid("37wn84845h89qs4hxvd075zr68").unwrap(),
+            expected
+        );
+        assert_eq!(
+            Uuid::from_ulid_or_uuid("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap(),
+            expected
+        );
+
+        // Ambiguous letters are read as the digits they look like
+        assert_eq!(
+            Uuid::from_ulid_or_uuid("O1ARZ3NDEKTSV4RRFFQ69G5FAV"),
+            Uuid::from_ulid_or_uuid("01ARZ3NDEKTSV4RRFFQ69G5FAV")
+        );
+        assert_eq!(
+            Uuid::from_ulid_or_uuid("0IARZ3NDEKTSV4RRFFQ69G5FAV"),
+            Uuid::from_ulid_or_uuid("0lARZ3NDEKTSV4RRFFQ69G5FAV")
+        );
+
+        assert_eq!(
+            Uuid::from_ulid_or_uuid("00000000000000000000000000").unwrap(),
+            Uuid::nil()
+        );
+        assert_eq!(
+            Uuid::from_ulid_or_uuid("7ZZZZZZZZZZZZZZZZZZZZZZZZZ").unwrap(),
+            Uuid::from_u128(u128::MAX)
+        );
+    }
+
+    #[test]
+    fn test_from_ulid_or_uuid_invalid() {
+        assert_eq!(
+            Uuid::from_ulid_or_uuid("67e5504410b1426f9247bb680e5fe0c8"),
+            Err(Error(ErrorKind::UlidLength { len: 32 }))
+        );
+
+        assert_eq!(
+            Uuid::from_ulid_or_uuid("{67e55044-10b1-426f-9247-bb680e5fe0c8}"),
+            Err(Error(ErrorKind::UlidLength { len: 38 }))
+        );
+
+        assert_eq!(
+            Uuid::from_ulid_or_uuid("8ZZZZZZZZZZZZZZZZZZZZZZZZZ"),
+            Err(Error(ErrorKind::Char {
+                character: '8',
+                index: 1,
+            }))
+        );
+
+        assert_eq!(
+            Uuid::from_ulid_or_uuid("01ARZ3NDEKTSV4RRFFQ69G5FAU"),
+            Err(Error(ErrorKind::Char {
+                character: 'U',
+                index: 26,
+            }))
+        );
+
+        assert!(Uuid::from_ulid_or_uuid("67e55044-10b1-426f-9247-bb680e5fe0cg").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_case_insensitive_str() {
+        use std::collections::HashMap;
+
+        let mut map = HashMap::new();
+
+        map.insert(
+            CaseInsensitiveUuidStr::new("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+            1,
+        );
+        map.insert(
+            CaseInsensitiveUuidStr::new("67E55044-10B1-426F-9247-BB680E5FE0C8"),
+            2,
+        );
+        map.insert(
+            CaseInsensitiveUuidStr::new("{67e55044-10b1-426f-9247-bb680e5fe0c8}"),
+            3,
+        );
+
+        assert_eq!(map.len(), 1);
+
+        // Inserting an equal key keeps the original one
+        let (key, value) = map.iter().next().unwrap();
+        assert_eq!(key.as_str(), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+        assert_eq!(*value, 3);
+
+        map.insert(CaseInsensitiveUuidStr::new("not a uuid"), 4);
+        map.insert(CaseInsensitiveUuidStr::new("NOT A UUID"), 5);
+
+        assert_eq!(map.len(), 3);
+        assert_eq!(map[&CaseInsensitiveUuidStr::new("not a uuid")], 4);
+        assert_eq!(CaseInsensitiveUuidStr::new("not a uuid").uuid(), None);
+    }
+
+    #[test]
+    fn test_from_urn_oid() {
+        assert_eq!(
+            Uuid::from_urn_oid("urn:oid:2.25.138101147531900207301164854559698313416"),
+            Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")
+        );
+        assert_eq!(
+            Uuid::from_urn_oid("URN:OID:2.25.138101147531900207301164854559698313416"),
+            Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")
+        );
+        assert_eq!(Uuid::from_urn_oid("urn:oid:2.25.0"), Ok(Uuid::nil()));
+        assert_eq!(
+            Uuid::from_urn_oid("urn:oid:2.25.340282366920938463463374607431768211455"),
+            Ok(Uuid::from_u128(u128::MAX))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_urn_oid_roundtrip() {
+        for uuid in [new(), Uuid::nil(), Uuid::from_u128(u128::MAX)] {
+            let urn = format!("urn:oid:{}", uuid.to_oid_string());
+
+            assert_eq!(Uuid::from_urn_oid(&urn), Ok(uuid));
+        }
+    }
+
+    #[test]
+    fn test_from_urn_oid_invalid() {
+        // 2^128
+        assert_eq!(
+            Uuid::from_urn_oid("urn:oid:2.25.340282366920938463463374607431768211456"),
+            Err(Error(ErrorKind::OidValue))
+        );
+        assert_eq!(
+            Uuid::from_urn_oid("urn:oid:2.25.3402823669209384634633746074317682114550"),
+            Err(Error(ErrorKind::OidValue))
+        );
+
+        assert_eq!(
+            Uuid::from_urn_oid("urn:oid:2.25."),
+            Err(Error(ErrorKind::OidValue))
+        );
+        assert_eq!(
+            Uuid::from_urn_oid("urn:oid:2.25.01"),
+            Err(Error(ErrorKind::OidValue))
+        );
+        assert_eq!(
+            Uuid::from_urn_oid("urn:oid:2.25.12a"),
+            Err(Error(ErrorKind::Char {
+                character: 'a',
+                index: 16,
+            }))
+        );
+
+        for input in [
+            "",
+            "urn:oid:2.26.1",
+            "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "2.25.1",
+            "urn:oid:2.25",
+            "urn:oid:2.2é.1",
+        ] {
+            assert_eq!(
+                Uuid::from_urn_oid(input),
+                Err(Error(ErrorKind::OidPrefix)),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_decimal_string() {
+        assert_eq!(Ok(Uuid::nil()), Uuid::from_decimal_string("0"));
+        assert_eq!(
+            Ok(Uuid::nil()),
+            Uuid::from_decimal_string("000000000000000000000000000000000000000")
+        );
+        assert_eq!(
+            Ok(Uuid::from_u128(u128::MAX)),
+            Uuid::from_decimal_string("340282366920938463463374607431768211455")
+        );
+        assert_eq!(Ok(Uuid::from_u128(42)), Uuid::from_decimal_string("0042"));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_decimal_string_roundtrip() {
+        for uuid in [
+            Uuid::nil(),
+            Uuid::from_u128(1),
+            Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8),
+            Uuid::from_u128(u128::MAX),
+        ] {
+            assert_eq!(
+                Ok(uuid),
+                Uuid::from_decimal_string(&uuid.to_decimal_string())
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_decimal_string_invalid() {
+        for input in [
+            "",
+            // 2^128
+            "340282366920938463463374607431768211456",
+            "3402823669209384634633746074317682114550",
+            // 40 digits
+            "0000000000000000000000000000000000000001",
+        ] {
+            assert_eq!(
+                Uuid::from_decimal_string(input),
+                Err(Error(ErrorKind::DecimalValue)),
+                "{}",
+                input
+            );
+        }
+
+        assert_eq!(
+            Uuid::from_decimal_string("12a"),
+            Err(Error(ErrorKind::Char {
+                character: 'a',
+                index: 3,
+            }))
+        );
+        assert_eq!(
+            Uuid::from_decimal_string("-1"),
+            Err(Error(ErrorKind::Char {
+                character: '-',
+                index: 1,
+            }))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_normalize_batch() {
+        use crate::std::string::ToString;
+
+        let canonical = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+        let results = Uuid::normalize_batch(&[
+            canonical,
+            "67E55044-10B1-426F-9247-BB680E5FE0C8",
+            "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+            "67e5504410b1426f9247bb680e5fe0c8",
+            "67e55044-10b1-426f-9247-bb680e5fe0c",
+        ]);
+
+        assert_eq!(results.len(), 5);
+        assert_eq!(results[0], Ok((canonical.to_string(), false)));
+        assert_eq!(results[1], Ok((canonical.to_string(), true)));
+        assert_eq!(results[2], Ok((canonical.to_string(), true)));
+        assert_eq!(results[3], Ok((canonical.to_string(), true)));
+        assert!(results[4].is_err());
+
+        assert!(Uuid::normalize_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_parse_wrapped() {
+        let expected = Uuid::from_u128(0xf9168c5e_ceb2_4faa_b6bf_329bf39fa1e4);
+
+        let valid = [
+            "F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4",
+            "{F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4}",
+            "urn:uuid:F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4",
+            "URN:UUID:F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4",
+            "Urn:Uuid:F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4",
+        ];
+
+        for input in valid.iter() {
+            assert_eq!(Ok(expected), Uuid::parse_str(input), "{}", input);
+            assert_eq!(Ok(expected), Uuid::try_parse(input), "{}", input);
+        }
+
+        let invalid = [
+            (
+                "{F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4",
+                ErrorKind::Char {
+                    character: '{',
+                    index: 1,
+                },
+            ),
+            (
+                "F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4}",
+                ErrorKind::Char {
+                    character: '}',
+                    index: 37,
+                },
+            ),
+            (
+                "urn:uuid:{F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4}",
+                ErrorKind::Char {
+                    character: '{',
+                    index: 10,
+                },
+            ),
+            (
+                "{urn:uuid:F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4}",
+                ErrorKind::Char {
+                    character: 'u',
+                    index: 2,
+                },
+            ),
+            (
+                "urn:uid:F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4",
+                ErrorKind::Char {
+                    character: 'u',
+                    index: 1,
+                },
+            ),
+        ];
+
+        for (input, kind) in invalid.iter().cloned() {
+            assert_eq!(Err(Error(kind)), Uuid::parse_str(input), "{}", input);
+            assert!(Uuid::try_parse(input).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_invalid_wrapped() {
+        assert_eq!(
+            Uuid::parse_str("{F9168C5E-CEB2-4faa-B6BF-329BF39FA1E45}"),
+            Err(Error(ErrorKind::GroupLength {
+                group: 4,
+                len: 13,
+                index: 26,
+                total: 39,
+                wrapper_len: 2,
+            }))
+        );
+
+        assert_eq!(
+            Uuid::parse_str("urn:uuid:F9168C5E-CEB2-4faa-B6BF-329BF39FA1E45"),
+            Err(Error(ErrorKind::GroupLength {
+                group: 4,
+                len: 13,
+                index: 34,
+                total: 46,
+                wrapper_len: 9,
+            }))
+        );
+
+        assert_eq!(
+            Uuid::parse_str("{}"),
+            Err(Error(ErrorKind::GroupCount {
+                count: 1,
+                total: 2,
+                wrapper_len: 2,
+            }))
+        );
+
+        assert_eq!(
+            Uuid::parse_str("urn:uuid:"),
+            Err(Error(ErrorKind::GroupCount {
+                count: 1,
+                total: 9,
+                wrapper_len: 9,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_never_panics() {
+        let valid = "F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4";
+
+        let inputs = [
+            "",
+            "{",
+            "}",
+            "-",
+            "----",
+            "{-}",
+            "urn:",
+            "urn:uuid",
+            "urn:uuid:{}",
+            "{urn:uuid:F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4}",
+            "F9168C5E-CEB2-4faa-B6BF-329BF39FA1E",
+            "F9168C5E-CEB2-4faa-B6BF-329BF39FA1E45",
+            "F9168C5E-CEB2-4faa-B6BF-329BF39FA1é",
+            "F9168C5E-CEB2-4faa-B6BF-329BF39FA1E😀",
+            "F9168C5E-CEB2-4faa-B6BF-329BF39FA-E4",
+            "F9168C5E-CEB2-4faa-B6BF-----------4",
+            "F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4-",
+            "-F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4",
+            "éF9168C5ECEB24faaB6BF329BF39FA1E",
+            "F9168C5ECEB24faaB6BF329BF39FA1éE",
+            "{F9168C5E-CEB2-4faa-B6BF-329BF39FA1E}",
+            "{F9168C5E-CEB2-4faa-B6BF-329BF39FA1E45}",
+            "\u{0}F9168C5E-CEB2-4faa-B6BF-329BF39FA1",
+        ];
+
+        for input in inputs.iter() {
+            assert!(Uuid::parse_str(input).is_err(), "{:?}", input);
+            assert!(Uuid::try_parse(input).is_err(), "{:?}", input);
+        }
+
+        let long = valid.repeat(64);
+        assert!(Uuid::parse_str(&long).is_err());
+
+        // Every char boundary prefix of a valid input must fail cleanly
+        let wrapped = "urn:uuid:F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4";
+        for i in 0..wrapped.len() {
+            let _ = Uuid::parse_str(&wrapped[..i]).map_err(|e| e.to_string());
+        }
+    }
+
+    #[test]
+    fn test_from_checked_str() {
+        let uuid = new();
+        let checked = format!("{}{}", uuid.hyphenated(), uuid.check_char());
+
+        #[cfg(feature = "std")]
+        assert_eq!(uuid.with_check_char(), checked);
+
+        assert_eq!(Uuid::from_checked_str(&checked), Ok(uuid));
+        assert_eq!(
+            Uuid::from_checked_str(&checked.to_ascii_lowercase()),
+            Ok(uuid)
+        );
+
+        let simple = format!("{}{}", uuid.simple(), uuid.check_char());
+        assert_eq!(Uuid::from_checked_str(&simple), Ok(uuid));
+
+        let braced = format!("{:X}{}", uuid.braced(), uuid.check_char());
+        assert_eq!(Uuid::from_checked_str(&braced), Ok(uuid));
+    }
+
+    #[test]
+    fn test_from_checked_str_detects_single_changes() {
+        let checked = format!("{}{}", new(), new().check_char());
+
+        for (i, c) in checked.char_indices() {
+            if c == '-' {
+                continue;
+            }
+
+            for replacement in "0123456789abcdefABCDEFGHJKMNPQRSTVWXYZ".chars() {
+                if replacement.eq_ignore_ascii_case(&c) {
+                    continue;
+                }
+
+                let mut altered = checked.clone();
+                altered.replace_range(i..i + 1, replacement.encode_utf8(&mut [0; 4]));
+
+                assert!(
+                    Uuid::from_checked_str(&altered).is_err(),
+                    "{} should be invalid",
+                    altered
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_checked_str_detects_transpositions() {
+        let checked = format!("{}{}", new(), new().check_char());
+        let simple: String = checked.chars().filter(|&c| c != '-').collect();
+
+        for i in 0..crate::fmt::Simple::LENGTH - 1 {
+            let mut altered = simple.clone().into_bytes();
+            altered.swap(i, i + 1);
+
+            if altered[i] == altered[i + 1] {
+                continue;
+            }
+
+            let altered = String::from_utf8(altered).unwrap();
+
+            assert!(
+                Uuid::from_checked_str(&altered).is_err(),
+                "{} should be invalid",
+                altered
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_checked_str_invalid() {
+        assert_eq!(
+            Uuid::from_checked_str(""),
+            Err(Error(ErrorKind::SimpleLength { len: 0 }))
+        );
+
+        assert_eq!(
+            Uuid::from_checked_str("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+            Err(Error(ErrorKind::GroupLength {
+                group: 4,
+                len: 11,
+                index: 25,
+                total: 35,
+                wrapper_len: 0,
+            }))
+        );
+
+        assert_eq!(
+            Uuid::from_checked_str("67e55044-10b1-426f-9247-bb680e5fe0c8B"),
+            Err(Error(ErrorKind::CheckChar { character: 'B' }))
+        );
+
+        assert_eq!(
+            Uuid::from_checked_str("67e55044-10b1-426f-9247-bb680e5fe0c8é"),
+            Err(Error(ErrorKind::CheckChar { character: 'é' }))
+        );
+    }
+
+    #[test]
+    fn test_parse_str_or_nil() {
+        let blank = ["", " ", "\t", "\r\n", " \u{3000} "];
+
+        for input in blank.iter() {
+            assert_eq!(
+                Uuid::parse_str_or_nil(input),
+                Ok(Uuid::nil()),
+                "{:?}",
+                input
+            );
+        }
+
+        assert_eq!(
+            Uuid::parse_str_or_nil("F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4"),
+            Ok(new())
+        );
+        assert_eq!(
+            Uuid::parse_str_or_nil("00000000-0000-0000-0000-000000000000"),
+            Ok(Uuid::nil())
+        );
+
+        assert_eq!(
+            Uuid::parse_str_or_nil("!"),
+            Err(Error(ErrorKind::Char {
+                character: '!',
+                index: 1,
+            }))
+        );
+        assert!(Uuid::parse_str_or_nil(" F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4").is_err());
+    }
+
+    #[test]
+    fn test_is_canonical_str() {
+        let canonical = [
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "00000000-0000-0000-0000-000000000000",
+        ];
+
+        for input in canonical.iter() {
+            assert!(Uuid::is_canonical_str(input), "{}", input);
+        }
+
+        assert!(Uuid::is_canonical_str(&new().to_string()));
+
+        // Parseable, but not canonical
+        let parseable = [
+            "67E55044-10B1-426F-9247-BB680E5FE0C8",
+            "67e55044-10b1-426f-9247-bb680e5fe0C8",
+            "67e5504410b1426f9247bb680e5fe0c8",
+            "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+            "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+        ];
+
+        for input in parseable.iter() {
+            assert!(Uuid::parse_str(input).is_ok(), "{}", input);
+            assert!(!Uuid::is_canonical_str(input), "{}", input);
+        }
+
+        // Not parseable at all
+        let invalid = [
+            "",
+            "67e55044-10b1-426f-9247-bb680e5fe0c",
+            "67e55044-10b1-426f-9247-bb680e5fe0c8a",
+            "67e5504410-b1-426f-9247-bb680e5fe0c8",
+            "67e55044-10b1-426f-9247-bb680e5fe0g8",
+            "67e55044-10b1-426f-9247-bb680e5fe0é",
+        ];
+
+        for input in invalid.iter() {
+            assert!(Uuid::parse_str(input).is_err(), "{}", input);
+            assert!(!Uuid::is_canonical_str(input), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_hex_decode_table() {
+        let digits = [(b'0'..=b'9', 0), (b'a'..=b'f', 10), (b'A'..=b'F', 10)];
+
+        let mut valid = 0;
+        for (range, offset) in digits.iter().cloned() {
+            for (value, b) in range.enumerate() {
+                assert_eq!(HEX_DECODE_TABLE[b as usize], value as u8 + offset);
+                valid += 1;
+            }
+        }
+
+        assert_eq!(valid, 22);
+        assert_eq!(
+            HEX_DECODE_TABLE.iter().filter(|&&v| v != 0xff).count(),
+            valid
+        );
+    }
+
+    #[test]
+    fn test_parse_str_detect() {
+        let cases = [
+            ("67e55044-10b1-426f-9247-bb680e5fe0c8", Format::Hyphenated),
+            ("67E55044-10B1-426F-9247-BB680E5FE0C8", Format::Hyphenated),
+            ("67e5504410b1426f9247bb680e5fe0c8", Format::Simple),
+            ("67E5504410B1426F9247BB680E5FE0C8", Format::Simple),
+            ("{67e55044-10b1-426f-9247-bb680e5fe0c8}", Format::Braced),
+            ("urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8", Format::Urn),
+        ];
+
+        let expected = Uuid::parse_str(cases[0].0).unwrap();
+
+        for &(input, format) in cases.iter() {
+            assert_eq!(Uuid::parse_str_detect(input), Ok((expected, format)));
+        }
+
+        let invalid = [
+            "",
+            "67e55044-10b1-426f-9247-bb680e5fe0c",
+            "{67e5504410b1426f9247bb680e5fe0c8}",
+        ];
+
+        for input in invalid {
+            assert_eq!(
+                Uuid::parse_str_detect(input).map(|(uuid, _)| uuid),
+                Uuid::parse_str(input)
+            );
+            assert!(Uuid::parse_str_detect(input).is_err());
+        }
+    }
+
+    #[test]
+    fn test_parse_with() {
+        use ParseMode::*;
+
+        let modes = [
+            Lenient,
+            CanonicalOnly,
+            HyphenatedOnly,
+            SimpleOnly,
+            Braced,
+            Urn,
+        ];
+
+        // Each input and whether it's accepted by each mode, in the order above
+        let cases = [
+            (
+                "67e55044-10b1-426f-9247-bb680e5fe0c8",
+                [true, true, true, false, false, false],
+            ),
+            (
+                "67E55044-10B1-426F-9247-BB680E5FE0C8",
+                [true, false, true, false, false, false],
+            ),
+            (
+                "67e5504410b1426f9247bb680e5fe0c8",
+                [true, false, false, true, false, false],
+            ),
+            (
+                "67E5504410B1426F9247BB680E5FE0C8",
+                [true, false, false, true, false, false],
+            ),
+            (
+                "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+                [true, false, false, false, true, false],
+            ),
+            (
+                "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+                [true, false, false, false, false, true],
+            ),
+            (
+                "{67e5504410b1426f9247bb680e5fe0c8}",
+                [false, false, false, false, false, false],
+            ),
+            (
+                "urn:uuid:67e5504410b1426f9247bb680e5fe0c8",
+                [false, false, false, false, false, false],
+            ),
+            (
+                "67e55044-10b1-426f-9247-bb680e5fe0c",
+                [false, false, false, false, false, false],
+            ),
+            ("", [false, false, false, false, false, false]),
+        ];
+
+        let expected = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+
+        for (input, accepted) in cases {
+            for (&mode, accepted) in modes.iter().zip(accepted) {
+                let result = Uuid::parse_with(input, mode);
+
+                assert_eq!(accepted, result.is_ok(), "{} {:?}", input, mode);
+
+                if let Ok(uuid) = result {
+                    assert_eq!(expected, uuid);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_with_errors() {
+        assert_eq!(
+            Uuid::parse_with("67e5504410b1426f9247bb680e5fe0c8", ParseMode::Urn),
+            Err(Error(ErrorKind::Mode {
+                mode: ParseMode::Urn
+            }))
+        );
+
+        // Invalid input gets the same errors as the lenient parser
+        for mode in [
+            ParseMode::CanonicalOnly,
+            ParseMode::HyphenatedOnly,
+            ParseMode::Braced,
+        ] {
+            assert_eq!(
+                Uuid::parse_with("{67e55044-10b1-426f-9247-bb680e5fe0c}", mode),
+                Uuid::parse_str("{67e55044-10b1-426f-9247-bb680e5fe0c}"),
+            );
+        }
+
+        assert_eq!(
+            Uuid::parse_with(
+                "67E55044-10B1-426F-9247-BB680E5FE0C8",
+                ParseMode::CanonicalOnly
+            )
+            .unwrap_err()
+            .to_string(),
+            "invalid format: expected the lower-case hyphenated format",
+        );
+    }
+
+    #[test]
+    fn test_try_parse_hyphenated() {
+        let expected = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+
+        assert_eq!(
+            Ok(expected),
+            Uuid::try_parse_hyphenated("67e55044-10b1-426f-9247-bb680e5fe0c8")
+        );
+        assert_eq!(
+            Ok(expected),
+            Uuid::try_parse_hyphenated("67E55044-10B1-426F-9247-BB680E5FE0C8")
+        );
+
+        for input in [
+            "67e5504410b1426f9247bb680e5fe0c8",
+            "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+            "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+        ] {
+            assert_eq!(
+                Uuid::try_parse_hyphenated(input),
+                Err(Error(ErrorKind::Mode {
+                    mode: ParseMode::HyphenatedOnly
+                })),
+                "{}",
+                input
+            );
+        }
+
+        // Invalid character
+        assert_eq!(
+            Uuid::try_parse_hyphenated("67e55044-10b1-426f-9247-bb680e5fe0cg"),
+            Err(Error(ErrorKind::Char {
+                character: 'g',
+                index: 36,
+            }))
+        );
+
+        // Wrong group length
+        assert_eq!(
+            Uuid::try_parse_hyphenated("67e55044-10b1-426f-9247b-b680e5fe0c8"),
+            Err(Error(ErrorKind::GroupLength {
+                group: 3,
+                len: 5,
+                index: 20,
+                total: 36,
+                wrapper_len: 0,
+            }))
+        );
+
+        // Missing hyphen
+        assert_eq!(
+            Uuid::try_parse_hyphenated("67e55044-10b1-426f-9247bb680e5fe0c8"),
+            Err(Error(ErrorKind::GroupCount {
+                count: 4,
+                total: 35,
+                wrapper_len: 0,
+            }))
+        );
+    }
+
     #[test]
     fn test_try_parse_ascii_non_utf8() {
         assert!(Uuid::try_parse_ascii(b"67e55044-10b1-426f-9247-bb680e5\0e0c8").is_err());
@@ -0,0 +1,735 @@
+//! Encoding UUIDs as words that are easy to read aloud.
+//!
+//! Note that you need to enable the `phonetic` Cargo feature
+//! in order to use this module.
+
+use crate::{
+    error::{Error, ErrorKind},
+    Uuid,
+};
+
+impl Uuid {
+    /// Encodes the UUID as a sequence of 16 words, one for each byte.
+    ///
+    /// The words come from the PGP word list, which has two lists of 256
+    /// words that alternate: even bytes use two-syllable words and odd bytes
+    /// use three-syllable words. The words were chosen to be easy to tell
+    /// apart when spoken, so this is useful for reading a UUID out over the
+    /// phone. Because the lists alternate, a repeated, dropped, or swapped
+    /// word can also be detected by [`Uuid::from_words`].
+    ///
+    /// Note that usage of this method requires the `phonetic` feature of this
+    /// crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("e58294f2-e9a2-2748-6e8b-061b31cc528f")?;
+    ///
+    /// assert_eq!(
+    ///     uuid.to_words()[..4],
+    ///     ["topmost", "Istanbul", "Pluto", "vagabond"],
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [PGP word list](https://en.wikipedia.org/wiki/PGP_word_list)
+    ///
+    /// [`Uuid::from_words`]: #method.from_words
+    pub const fn to_words(&self) -> [&'static str; 16] {
+        let mut words = [""; 16];
+
+        let mut i = 0;
+        while i < 16 {
+            words[i] = list(i)[self.0[i] as usize];
+            i += 1;
+        }
+
+        words
+    }
+
+    /// Decodes a UUID from the sequence of 16 words produced by
+    /// [`Uuid::to_words`].
+    ///
+    /// Words are compared ignoring ASCII case. Each word must come from the
+    /// list for its position, so words that are out of order are rejected.
+    ///
+    /// Note that usage of this method requires the `phonetic` feature of this
+    /// crate to be enabled.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if there aren't exactly 16 words,
+    /// or if any word isn't in the list for its position.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("e58294f2-e9a2-2748-6e8b-061b31cc528f")?;
+    ///
+    /// let words = uuid.to_words();
+    ///
+    /// assert_eq!(uuid, Uuid::from_words(&words)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Uuid::to_words`]: #method.to_words
+    pub fn from_words(words: &[&str]) -> Result<Uuid, Error> {
+        if words.len() != 16 {
+            return Err(Error(ErrorKind::WordCount { count: words.len() }));
+        }
+
+        let mut bytes = [0; 16];
+
+        for (index, word) in words.iter().enumerate() {
+            bytes[index] = list(index)
+                .iter()
+                .position(|candidate| candidate.eq_ignore_ascii_case(word))
+                .ok_or(Error(ErrorKind::Word { index }))? as u8;
+        }
+
+        Ok(Uuid::from_bytes(bytes))
+    }
+}
+
+/// The word list used for the byte at `index`.
+const fn list(index: usize) -> &'static [&'static str; 256] {
+    if index & 1 == 0 {
+        &EVEN
+    } else {
+        &ODD
+    }
+}
+
+/// Two-syllable words, used for bytes at even positions.
+const EVEN: [&str; 256] = [
+    "aardvark",
+    "absurd",
+    "accrue",
+    "acme",
+    "adrift",
+    "adult",
+    "afflict",
+    "ahead",
+    "aimless",
+    "Algol",
+    "allow",
+    "alone",
+    "ammo",
+    "ancient",
+    "apple",
+    "artist",
+    "assume",
+    "Athens",
+    "atlas",
+    "Aztec",
+    "baboon",
+    "backfield",
+    "backward",
+    "banjo",
+    "beaming",
+    "bedlamp",
+    "beehive",
+    "beeswax",
+    "befriend",
+    "Belfast",
+    "berserk",
+    "billiard",
+    "bison",
+    "blackjack",
+    "blockade",
+    "blowtorch",
+    "bluebird",
+    "bombast",
+    "bookshelf",
+    "brackish",
+    "breadline",
+    "breakup",
+    "brickyard",
+    "briefcase",
+    "Burbank",
+    "button",
+    "buzzard",
+    "cement",
+    "chairlift",
+    "chatter",
+    "checkup",
+    "chisel",
+    "choking",
+    "chopper",
+    "Christmas",
+    "clamshell",
+    "classic",
+    "classroom",
+    "cleanup",
+    "clockwork",
+    "cobra",
+    "commence",
+    "concert",
+    "cowbell",
+    "crackdown",
+    "cranky",
+    "crowfoot",
+    "crucial",
+    "crumpled",
+    "crusade",
+    "cubic",
+    "dashboard",
+    "deadbolt",
+    "deckhand",
+    "dogsled",
+    "dragnet",
+    "drainage",
+    "dreadful",
+    "drifter",
+    "dropper",
+    "drumbeat",
+    "drunken",
+    "Dupont",
+    "dwelling",
+    "eating",
+    "edict",
+    "egghead",
+    "eightball",
+    "endorse",
+    "endow",
+    "enlist",
+    "erase",
+    "escape",
+    "exceed",
+    "eyeglass",
+    "eyetooth",
+    "facial",
+    "fallout",
+    "flagpole",
+    "flatfoot",
+    "flytrap",
+    "fracture",
+    "framework",
+    "freedom",
+    "frighten",
+    "gazelle",
+    "Geiger",
+    "glitter",
+    "glucose",
+    "goggles",
+    "goldfish",
+    "gremlin",
+    "guidance",
+    "hamlet",
+    "highchair",
+    "hockey",
+    "indoors",
+    "indulge",
+    "inverse",
+    "involve",
+    "island",
+    "jawbone",
+    "keyboard",
+    "kickoff",
+    "kiwi",
+    "klaxon",
+    "locale",
+    "lockup",
+    "merit",
+    "minnow",
+    "miser",
+    "Mohawk",
+    "mural",
+    "music",
+    "necklace",
+    "Neptune",
+    "newborn",
+    "nightbird",
+    "Oakland",
+    "obtuse",
+    "offload",
+    "optic",
+    "orca",
+    "payday",
+    "peachy",
+    "pheasant",
+    "physique",
+    "playhouse",
+    "Pluto",
+    "preclude",
+    "prefer",
+    "preshrunk",
+    "printer",
+    "prowler",
+    "pupil",
+    "puppy",
+    "python",
+    "quadrant",
+    "quiver",
+    "quota",
+    "ragtime",
+    "ratchet",
+    "rebirth",
+    "reform",
+    "regain",
+    "reindeer",
+    "rematch",
+    "repay",
+    "retouch",
+    "revenge",
+    "reward",
+    "rhythm",
+    "ribcage",
+    "ringbolt",
+    "robust",
+    "rocker",
+    "ruffled",
+    "sailboat",
+    "sawdust",
+    "scallion",
+    "scenic",
+    "scorecard",
+    "Scotland",
+    "seabird",
+    "select",
+    "sentence",
+    "shadow",
+    "shamrock",
+    "showgirl",
+    "skullcap",
+    "skydive",
+    "slingshot",
+    "slowdown",
+    "snapline",
+    "snapshot",
+    "snowcap",
+    "snowslide",
+    "solo",
+    "southward",
+    "soybean",
+    "spaniel",
+    "spearhead",
+    "spellbind",
+    "spheroid",
+    "spigot",
+    "spindle",
+    "spyglass",
+    "stagehand",
+    "stagnate",
+    "stairway",
+    "standard",
+    "stapler",
+    "steamship",
+    "sterling",
+    "stockman",
+    "stopwatch",
+    "stormy",
+    "sugar",
+    "surmount",
+    "suspense",
+    "sweatband",
+    "swelter",
+    "tactics",
+    "talon",
+    "tapeworm",
+    "tempest",
+    "tiger",
+    "tissue",
+    "tonic",
+    "topmost",
+    "tracker",
+    "transit",
+    "trauma",
+    "treadmill",
+    "Trojan",
+    "trouble",
+    "tumor",
+    "tunnel",
+    "tycoon",
+    "uncut",
+    "unearth",
+    "unwind",
+    "uproot",
+    "upset",
+    "upshot",
+    "vapor",
+    "village",
+    "virus",
+    "Vulcan",
+    "waffle",
+    "wallet",
+    "watchword",
+    "wayside",
+    "willow",
+    "woodlark",
+    "Zulu",
+];
+
+/// Three-syllable words, used for bytes at odd positions.
+const ODD: [&str; 256] = [
+    "adroitness",
+    "adviser",
+    "aftermath",
+    "aggregate",
+    "alkali",
+    "almighty",
+    "amulet",
+    "amusement",
+    "antenna",
+    "applicant",
+    "Apollo",
+    "armistice",
+    "article",
+    "asteroid",
+    "Atlantic",
+    "atmosphere",
+    "autopsy",
+    "Babylon",
+    "backwater",
+    "barbecue",
+    "belowground",
+    "bifocals",
+    "bodyguard",
+    "bookseller",
+    "borderline",
+    "bottomless",
+    "Bradbury",
+    "bravado",
+    "Brazilian",
+    "breakaway",
+    "Burlington",
+    "businessman",
+    "butterfat",
+    "Camelot",
+    "candidate",
+    "cannonball",
+    "Capricorn",
+    "caravan",
+    "caretaker",
+    "celebrate",
+    "cellulose",
+    "certify",
+    "chambermaid",
+    "Cherokee",
+    "Chicago",
+    "clergyman",
+    "coherence",
+    "combustion",
+    "commando",
+    "company",
+    "component",
+    "concurrent",
+    "confidence",
+    "conformist",
+    "congregate",
+    "consensus",
+    "consulting",
+    "corporate",
+    "corrosion",
+    "councilman",
+    "crossover",
+    "crucifix",
+    "cumbersome",
+    "customer",
+    "Dakota",
+    "decadence",
+    "December",
+    "decimal",
+    "designing",
+    "detector",
+    "detergent",
+    "determine",
+    "dictator",
+    "dinosaur",
+    "direction",
+    "disable",
+    "disbelief",
+    "disruptive",
+    "distortion",
+    "document",
+    "embezzle",
+    "enchanting",
+    "enrollment",
+    "enterprise",
+    "equation",
+    "equipment",
+    "escapade",
+    "Eskimo",
+    "everyday",
+    "examine",
+    "existence",
+    "exodus",
+    "fascinate",
+    "filament",
+    "finicky",
+    "forever",
+    "fortitude",
+    "frequency",
+    "gadgetry",
+    "Galveston",
+    "getaway",
+    "glossary",
+    "gossamer",
+    "graduate",
+    "gravity",
+    "guitarist",
+    "hamburger",
+    "Hamilton",
+    "handiwork",
+    "hazardous",
+    "headwaters",
+    "hemisphere",
+    "hesitate",
+    "hideaway",
+    "holiness",
+    "hurricane",
+    "hydraulic",
+    "impartial",
+    "impetus",
+    "inception",
+    "indigo",
+    "inertia",
+    "infancy",
+    "inferno",
+    "informant",
+    "insincere",
+    "insurgent",
+    "integrate",
+    "intention",
+    "inventive",
+    "Istanbul",
+    "Jamaica",
+    "Jupiter",
+    "leprosy",
+    "letterhead",
+    "liberty",
+    "maritime",
+    "matchmaker",
+    "maverick",
+    "Medusa",
+    "megaton",
+    "microscope",
+    "microwave",
+    "midsummer",
+    "millionaire",
+    "miracle",
+    "misnomer",
+    "molasses",
+    "molecule",
+    "Montana",
+    "monument",
+    "mosquito",
+    "narrative",
+    "nebula",
+    "newsletter",
+    "Norwegian",
+    "October",
+    "Ohio",
+    "onlooker",
+    "opulent",
+    "Orlando",
+    "outfielder",
+    "Pacific",
+    "pandemic",
+    "Pandora",
+    "paperweight",
+    "paragon",
+    "paragraph",
+    "paramount",
+    "passenger",
+    "pedigree",
+    "Pegasus",
+    "penetrate",
+    "perceptive",
+    "performance",
+    "pharmacy",
+    "phonetic",
+    "photograph",
+    "pioneer",
+    "pocketful",
+    "politeness",
+    "positive",
+    "potato",
+    "processor",
+    "provincial",
+    "proximate",
+    "puberty",
+    "publisher",
+    "pyramid",
+    "quantity",
+    "racketeer",
+    "rebellion",
+    "recipe",
+    "recover",
+    "repellent",
+    "replica",
+    "reproduce",
+    "resistor",
+    "responsive",
+    "retraction",
+    "retrieval",
+    "retrospect",
+    "revenue",
+    "revival",
+    "revolver",
+    "sandalwood",
+    "sardonic",
+    "Saturday",
+    "savagery",
+    "scavenger",
+    "sensation",
+    "sociable",
+    "souvenir",
+    "specialist",
+    "speculate",
+    "stethoscope",
+    "stupendous",
+    "supportive",
+    "surrender",
+    "suspicious",
+    "sympathy",
+    "tambourine",
+    "telephone",
+    "therapist",
+    "tobacco",
+    "tolerance",
+    "tomorrow",
+    "torpedo",
+    "tradition",
+    "travesty",
+    "trombonist",
+    "truncated",
+    "typewriter",
+    "ultimate",
+    "undaunted",
+    "underfoot",
+    "unicorn",
+    "unify",
+    "universe",
+    "unravel",
+    "upcoming",
+    "vacancy",
+    "vagabond",
+    "vertigo",
+    "Virginia",
+    "visitor",
+    "vocalist",
+    "voyager",
+    "warranty",
+    "Waterloo",
+    "whimsical",
+    "Wichita",
+    "Wilmington",
+    "Wyoming",
+    "yesteryear",
+    "Yucatan",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{new, xorshift};
+
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::*;
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_to_words() {
+        let uuid = Uuid::parse_str("e58294f2-e9a2-2748-6e8b-061b31cc528f").unwrap();
+
+        assert_eq!(
+            uuid.to_words(),
+            [
+                "topmost",
+                "Istanbul",
+                "Pluto",
+                "vagabond",
+                "treadmill",
+                "Pacific",
+                "brackish",
+                "dictator",
+                "goldfish",
+                "Medusa",
+                "afflict",
+                "bravado",
+                "chatter",
+                "revolver",
+                "Dupont",
+                "midsummer",
+            ]
+        );
+
+        assert_eq!(Uuid::nil().to_words()[..2], ["aardvark", "adroitness"]);
+        assert_eq!(
+            Uuid::from_bytes([0xff; 16]).to_words()[..2],
+            ["Zulu", "Yucatan"]
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_words_roundtrip() {
+        let mut random_bytes = xorshift(0x70c);
+
+        for _ in 0..1_000 {
+            let uuid = Uuid::from_bytes(random_bytes());
+
+            assert_eq!(Uuid::from_words(&uuid.to_words()), Ok(uuid));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_from_words_ignores_case() {
+        let uuid = new();
+        let mut words = uuid.to_words();
+        words[0] = "ZULU";
+        words[1] = "YUCATAN";
+
+        let expected = Uuid::from_bytes({
+            let mut bytes = *uuid.as_bytes();
+            bytes[0] = 0xff;
+            bytes[1] = 0xff;
+            bytes
+        });
+
+        assert_eq!(Uuid::from_words(&words), Ok(expected));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_from_words_invalid() {
+        let words = new().to_words();
+
+        assert_eq!(
+            Uuid::from_words(&words[..15]),
+            Err(Error(ErrorKind::WordCount { count: 15 }))
+        );
+
+        let mut unknown = words;
+        unknown[3] = "uuid";
+
+        assert_eq!(
+            Uuid::from_words(&unknown),
+            Err(Error(ErrorKind::Word { index: 3 }))
+        );
+
+        // Swapping two words puts them in the wrong lists
+        let mut swapped = words;
+        swapped.swap(4, 5);
+
+        assert_eq!(
+            Uuid::from_words(&swapped),
+            Err(Error(ErrorKind::Word { index: 4 }))
+        );
+    }
+}
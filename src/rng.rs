@@ -18,6 +18,22 @@ pub(crate) fn bytes() -> [u8; 16] {
     }
 }
 
+#[cfg(feature = "v4")]
+pub(crate) fn fill(dest: &mut [u8]) {
+    #[cfg(not(feature = "fast-rng"))]
+    {
+        getrandom::getrandom(dest).unwrap_or_else(|err| {
+            // NB: getrandom::Error has no source; this is adequate display
+            panic!("could not retrieve random bytes for uuid: {}", err)
+        });
+    }
+
+    #[cfg(feature = "fast-rng")]
+    {
+        rand::RngCore::fill_bytes(&mut rand::thread_rng(), dest)
+    }
+}
+
 #[cfg(any(feature = "v1", feature = "v6"))]
 pub(crate) fn u16() -> u16 {
     #[cfg(not(feature = "fast-rng"))]
@@ -37,3 +53,23 @@ pub(crate) fn u16() -> u16 {
         rand::random()
     }
 }
+
+#[cfg(all(uuid_unstable, feature = "v6", feature = "std", feature = "rng"))]
+pub(crate) fn node_id() -> [u8; 6] {
+    #[cfg(not(feature = "fast-rng"))]
+    {
+        let mut bytes = [0u8; 6];
+
+        getrandom::getrandom(&mut bytes).unwrap_or_else(|err| {
+            // NB: getrandom::Error has no source; this is adequate display
+            panic!("could not retrieve random bytes for uuid: {}", err)
+        });
+
+        bytes
+    }
+
+    #[cfg(feature = "fast-rng")]
+    {
+        rand::random()
+    }
+}
@@ -1,14 +1,35 @@
 #[cfg(feature = "v5")]
 pub(crate) fn hash(ns: &[u8], src: &[u8]) -> [u8; 16] {
-    use sha1_smol::Sha1;
+    let mut hasher = Hasher::new(ns);
 
-    let mut hasher = Sha1::new();
-
-    hasher.update(ns);
     hasher.update(src);
 
-    let mut bytes = [0; 16];
-    bytes.copy_from_slice(&hasher.digest().bytes()[..16]);
+    hasher.finalize()
+}
+
+/// A SHA-1 hash of a namespace followed by a name that's fed in incrementally.
+#[cfg(feature = "v5")]
+#[derive(Clone)]
+pub(crate) struct Hasher(sha1_smol::Sha1);
+
+#[cfg(feature = "v5")]
+impl Hasher {
+    pub(crate) fn new(ns: &[u8]) -> Self {
+        let mut hasher = sha1_smol::Sha1::new();
+
+        hasher.update(ns);
+
+        Hasher(hasher)
+    }
+
+    pub(crate) fn update(&mut self, src: &[u8]) {
+        self.0.update(src);
+    }
+
+    pub(crate) fn finalize(self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        bytes.copy_from_slice(&self.0.digest().bytes()[..16]);
 
-    bytes
+        bytes
+    }
 }
@@ -88,6 +88,27 @@ impl Timestamp {
         }
     }
 
+    /// Construct a `Timestamp` from a count of 100 nanosecond ticks since the
+    /// Gregorian calendar reform (`1582-10-15 00:00:00`) and a counter.
+    ///
+    /// This is the same as [`Timestamp::from_rfc4122`]. The offset between
+    /// the Gregorian and Unix epochs is [`UUID_TICKS_BETWEEN_EPOCHS`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{timestamp::UUID_TICKS_BETWEEN_EPOCHS, Timestamp};
+    /// let ts = Timestamp::from_gregorian(UUID_TICKS_BETWEEN_EPOCHS + 10_000_005, 0);
+    ///
+    /// assert_eq!((1, 500), ts.to_unix());
+    /// ```
+    ///
+    /// [`Timestamp::from_rfc4122`]: #method.from_rfc4122
+    /// [`UUID_TICKS_BETWEEN_EPOCHS`]: constant.UUID_TICKS_BETWEEN_EPOCHS.html
+    pub const fn from_gregorian(ticks: u64, counter: u16) -> Self {
+        Self::from_rfc4122(ticks, counter)
+    }
+
     /// Construct a `Timestamp` from a Unix timestamp, as used in version 7 UUIDs.
     pub fn from_unix(context: impl ClockSequence<Output = u16>, seconds: u64, nanos: u32) -> Self {
         #[cfg(not(any(feature = "v1", feature = "v6")))]
@@ -125,9 +146,7 @@ impl Timestamp {
 
     #[cfg(any(feature = "v1", feature = "v6"))]
     const fn unix_to_rfc4122_ticks(seconds: u64, nanos: u32) -> u64 {
-        let ticks = UUID_TICKS_BETWEEN_EPOCHS + seconds * 10_000_000 + nanos as u64 / 100;
-
-        ticks
+        UUID_TICKS_BETWEEN_EPOCHS + seconds * 10_000_000 + nanos as u64 / 100
     }
 
     const fn rfc4122_to_unix(ticks: u64) -> (u64, u32) {
@@ -311,13 +330,52 @@ pub trait ClockSequence {
     fn generate_sequence(&self, seconds: u64, subsec_nanos: u32) -> Self::Output;
 }
 
-impl<'a, T: ClockSequence + ?Sized> ClockSequence for &'a T {
+impl<T: ClockSequence + ?Sized> ClockSequence for &T {
     type Output = T::Output;
     fn generate_sequence(&self, seconds: u64, subsec_nanos: u32) -> Self::Output {
         (**self).generate_sequence(seconds, subsec_nanos)
     }
 }
 
+/// A source of Unix timestamps in milliseconds, as used by version 7 UUIDs.
+///
+/// Version 7 UUIDs are normally generated from the system time. Passing a
+/// different clock to [`Uuid::new_v7_with_clock`] makes the timestamps they
+/// contain predictable, which is useful for testing code that depends on
+/// their ordering.
+///
+/// [`Uuid::new_v7_with_clock`]: ../struct.Uuid.html#method.new_v7_with_clock
+#[cfg(uuid_unstable)]
+pub trait Clock {
+    /// Get the current time as a number of milliseconds since the Unix epoch.
+    fn now_millis(&self) -> u64;
+}
+
+#[cfg(uuid_unstable)]
+impl<T: Clock + ?Sized> Clock for &T {
+    fn now_millis(&self) -> u64 {
+        (**self).now_millis()
+    }
+}
+
+/// A clock that reads the current system time.
+///
+/// This is the clock used by [`Uuid::now_v7`].
+///
+/// [`Uuid::now_v7`]: ../struct.Uuid.html#method.now_v7
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg(all(uuid_unstable, feature = "std"))]
+pub struct SystemClock;
+
+#[cfg(all(uuid_unstable, feature = "std"))]
+impl Clock for SystemClock {
+    fn now_millis(&self) -> u64 {
+        let (seconds, nanos) = now();
+
+        (seconds * 1000).saturating_add(nanos as u64 / 1_000_000)
+    }
+}
+
 /// Default implementations for the [`ClockSequence`] trait.
 pub mod context {
     use super::ClockSequence;
@@ -25,6 +25,37 @@ impl Uuid {
         Self::new_v1(ts, node_id)
     }
 
+    /// Create a new version 1 UUID using the current system time and node ID,
+    /// and return it along with the timestamp it contains.
+    ///
+    /// This is like [`Uuid::now_v1`], but also returns the exact timestamp
+    /// and clock sequence that were encoded into the UUID. The returned
+    /// [`Timestamp`] is truncated to the 100ns precision of a version 1 UUID,
+    /// so it's always equal to the result of [`Uuid::get_timestamp`].
+    ///
+    /// Note that usage of this method requires the `v1`, `std`, and `rng` features of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let (uuid, ts) = Uuid::now_v1_with_timestamp(&[1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!(Some(ts), uuid.get_timestamp());
+    /// ```
+    ///
+    /// [`Timestamp`]: struct.Timestamp.html
+    /// [`Uuid::get_timestamp`]: #method.get_timestamp
+    #[cfg(all(feature = "std", feature = "rng"))]
+    pub fn now_v1_with_timestamp(node_id: &[u8; 6]) -> (Self, Timestamp) {
+        let uuid = Self::now_v1(node_id);
+
+        let (ticks, counter) = crate::timestamp::decode_rfc4122_timestamp(&uuid);
+
+        (uuid, Timestamp::from_rfc4122(ticks, counter))
+    }
+
     /// Create a new version 1 UUID using the given timestamp and node ID.
     ///
     /// Also see [`Uuid::now_v1`] for a convenient way to generate version 1
@@ -42,6 +73,12 @@ impl Uuid {
     ///    purpose. However you can create your own [`ClockSequence`]
     ///    implementation, if [`Context`] does not meet your needs).
     ///
+    /// This function doesn't keep any state of its own. The clock sequence
+    /// is whatever was captured in `ts` when it was created, so if `ts`
+    /// comes from a source without a [`ClockSequence`], like
+    /// [`Timestamp::from_rfc4122`], then keeping UUIDs unique is up to the
+    /// caller.
+    ///
     /// Note that usage of this method requires the `v1` feature of this crate
     /// to be enabled.
     ///
@@ -86,6 +123,7 @@ impl Uuid {
     /// * [Version 1 UUIDs in RFC4122](https://www.rfc-editor.org/rfc/rfc4122#section-4.2)
     ///
     /// [`Timestamp`]: v1/struct.Timestamp.html
+    /// [`Timestamp::from_rfc4122`]: v1/struct.Timestamp.html#method.from_rfc4122
     /// [`ClockSequence`]: v1/trait.ClockSequence.html
     /// [`Context`]: v1/struct.Context.html
     pub fn new_v1(ts: Timestamp, node_id: &[u8; 6]) -> Self {
@@ -133,6 +171,48 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_fields() {
+        let ticks: u64 = 0x0fed_cba9_8765_4321;
+        let counter: u16 = 0x2abc;
+        let node = [1, 2, 3, 4, 5, 6];
+
+        let uuid = Uuid::new_v1(Timestamp::from_rfc4122(ticks, counter), &node);
+
+        // The timestamp is split across the first three fields, lowest bits first
+        let decoded = ((uuid.time_hi_and_version() & 0x0fff) as u64) << 48
+            | (uuid.time_mid() as u64) << 32
+            | uuid.time_low() as u64;
+
+        assert_eq!(decoded, ticks);
+        assert_eq!(uuid.time_low(), 0x8765_4321);
+        assert_eq!(uuid.time_mid(), 0xcba9);
+        assert_eq!(uuid.time_hi_and_version(), 0x1fed);
+        assert_eq!(uuid.clock_seq() & 0x3fff, counter);
+        assert_eq!(uuid.node(), node);
+        assert_eq!(uuid.get_timestamp().unwrap().to_rfc4122(), (ticks, counter));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(all(feature = "std", feature = "rng"))]
+    fn test_now_with_timestamp() {
+        let node = [1, 2, 3, 4, 5, 6];
+
+        let (uuid, ts) = Uuid::now_v1_with_timestamp(&node);
+
+        assert_eq!(uuid.get_version(), Some(Version::Mac));
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+        assert_eq!(uuid.get_timestamp(), Some(ts));
+
+        let (ticks, counter) = ts.to_rfc4122();
+        assert_eq!(
+            Uuid::new_v1(Timestamp::from_rfc4122(ticks, counter), &node),
+            uuid
+        );
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     #[cfg(all(feature = "std", feature = "rng"))]
@@ -1,4 +1,4 @@
-use crate::Uuid;
+use crate::{std::fmt, Uuid};
 
 impl Uuid {
     /// Creates a UUID using a name from a namespace, based on the MD5
@@ -25,6 +25,19 @@ impl Uuid {
     /// assert_eq!(Some(Version::Md5), uuid.get_version());
     /// ```
     ///
+    /// The UUID for a name is always the same, so it can be checked against
+    /// other implementations:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::new_v3(&Uuid::NAMESPACE_DNS, b"example.org");
+    ///
+    /// assert_eq!(
+    ///     "04738bdf-b25a-3829-a801-b21a1d25095b",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    ///
     /// # References
     ///
     /// * [Version 3 and 5 UUIDs in RFC4122](https://www.rfc-editor.org/rfc/rfc4122#section-4.3)
@@ -36,6 +49,98 @@ impl Uuid {
     pub fn new_v3(namespace: &Uuid, name: &[u8]) -> Uuid {
         crate::Builder::from_md5_bytes(crate::md5::hash(namespace.as_bytes(), name)).into_uuid()
     }
+
+    /// Checks whether this UUID is the version 3 UUID of a name in a namespace.
+    ///
+    /// Returns `true` if hashing `name` in `namespace` with [`Uuid::new_v3`]
+    /// produces exactly this UUID.
+    ///
+    /// Note that usage of this method requires the `v3` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::new_v3(&Uuid::NAMESPACE_DNS, b"rust-lang.org");
+    ///
+    /// assert!(uuid.is_v3_of(&Uuid::NAMESPACE_DNS, b"rust-lang.org"));
+    /// assert!(!uuid.is_v3_of(&Uuid::NAMESPACE_DNS, b"crates.io"));
+    /// ```
+    ///
+    /// [`Uuid::new_v3`]: #method.new_v3
+    pub fn is_v3_of(&self, namespace: &Uuid, name: &[u8]) -> bool {
+        *self == Uuid::new_v3(namespace, name)
+    }
+
+    /// Creates a [`V3Hasher`] that builds a version 3 UUID from a name
+    /// in a namespace that's fed in as a sequence of chunks.
+    ///
+    /// This produces the same UUID as [`Uuid::new_v3`] on the chunks joined
+    /// together, without needing to buffer the whole name first. It's useful
+    /// for deriving a UUID from a large input like the contents of a file.
+    ///
+    /// Note that usage of this method requires the `v3` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let mut hasher = Uuid::v3_hasher(&Uuid::NAMESPACE_DNS);
+    ///
+    /// hasher.update(b"rust-");
+    /// hasher.update(b"lang.org");
+    ///
+    /// assert_eq!(
+    ///     Uuid::new_v3(&Uuid::NAMESPACE_DNS, b"rust-lang.org"),
+    ///     hasher.finalize(),
+    /// );
+    /// ```
+    ///
+    /// [`V3Hasher`]: struct.V3Hasher.html
+    /// [`Uuid::new_v3`]: #method.new_v3
+    pub fn v3_hasher(namespace: &Uuid) -> V3Hasher {
+        V3Hasher(crate::md5::Hasher::new(namespace.as_bytes()))
+    }
+}
+
+/// An incremental builder for version 3 UUIDs, based on the MD5 hash.
+///
+/// This is created by [`Uuid::v3_hasher`]. The namespace is hashed
+/// first, then each chunk of the name passed to
+/// [`V3Hasher::update`], in order. Calling [`V3Hasher::finalize`] sets the
+/// version and variant and returns the UUID.
+///
+/// Note that usage of this type requires the `v3` feature of this crate
+/// to be enabled.
+///
+/// [`Uuid::v3_hasher`]: struct.Uuid.html#method.v3_hasher
+/// [`V3Hasher::update`]: #method.update
+/// [`V3Hasher::finalize`]: #method.finalize
+#[derive(Clone)]
+pub struct V3Hasher(crate::md5::Hasher);
+
+impl V3Hasher {
+    /// Feeds the next chunk of the name into the hash.
+    pub fn update(&mut self, chunk: &[u8]) {
+        self.0.update(chunk);
+    }
+
+    /// Finishes hashing the name and returns the version 3 UUID.
+    pub fn finalize(self) -> Uuid {
+        crate::Builder::from_md5_bytes(self.0.finalize()).into_uuid()
+    }
+}
+
+impl fmt::Debug for V3Hasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("V3Hasher").finish_non_exhaustive()
+    }
 }
 
 #[cfg(test)]
@@ -47,7 +152,7 @@ mod tests {
 
     use crate::{std::string::ToString, Variant, Version};
 
-    static FIXTURE: &'static [(&'static Uuid, &'static str, &'static str)] = &[
+    static FIXTURE: &[(&Uuid, &str, &str)] = &[
         (
             &Uuid::NAMESPACE_DNS,
             "example.org",
@@ -133,19 +238,73 @@ mod tests {
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn test_new() {
-        for &(ref ns, ref name, _) in FIXTURE {
-            let uuid = Uuid::new_v3(*ns, name.as_bytes());
+        for (ns, name, _) in FIXTURE {
+            let uuid = Uuid::new_v3(ns, name.as_bytes());
             assert_eq!(uuid.get_version(), Some(Version::Md5));
             assert_eq!(uuid.get_variant(), Variant::RFC4122);
         }
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_known_vector() {
+        let uuid = Uuid::new_v3(&Uuid::NAMESPACE_DNS, b"example.org");
+
+        assert_eq!(
+            uuid,
+            Uuid::from_u128(0x04738bdf_b25a_3829_a801_b21a1d25095b)
+        );
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn test_hyphenated_string() {
-        for &(ref ns, ref name, ref expected) in FIXTURE {
-            let uuid = Uuid::new_v3(*ns, name.as_bytes());
+        for (ns, name, expected) in FIXTURE {
+            let uuid = Uuid::new_v3(ns, name.as_bytes());
             assert_eq!(uuid.hyphenated().to_string(), *expected);
         }
     }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_is_v3_of() {
+        for (ns, name, u) in FIXTURE {
+            let uuid: Uuid = u.parse().unwrap();
+
+            assert!(uuid.is_v3_of(ns, name.as_bytes()));
+            assert!(!uuid.is_v3_of(ns, b"not the name"));
+            assert!(!uuid.is_v3_of(&Uuid::nil(), name.as_bytes()));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_hasher() {
+        for (ns, name, u) in FIXTURE {
+            let expected: Uuid = u.parse().unwrap();
+
+            // Every way of splitting the name into two chunks
+            for split in 0..=name.len() {
+                let mut hasher = Uuid::v3_hasher(ns);
+
+                hasher.update(&name.as_bytes()[..split]);
+                hasher.update(&name.as_bytes()[split..]);
+
+                assert_eq!(hasher.finalize(), expected);
+            }
+
+            let mut hasher = Uuid::v3_hasher(ns);
+            for byte in name.as_bytes() {
+                hasher.update(&[*byte]);
+            }
+
+            assert_eq!(hasher.clone().finalize(), expected);
+            assert_eq!(hasher.finalize(), Uuid::new_v3(ns, name.as_bytes()));
+        }
+
+        assert_eq!(
+            Uuid::v3_hasher(&Uuid::NAMESPACE_DNS).finalize(),
+            Uuid::new_v3(&Uuid::NAMESPACE_DNS, b"")
+        );
+    }
 }
@@ -1,4 +1,10 @@
-use crate::Uuid;
+use crate::{
+    std::{convert::TryInto, fmt},
+    Builder, Uuid,
+};
+
+#[cfg(feature = "rand_core")]
+use crate::error::{Error, ErrorKind};
 
 impl Uuid {
     /// Creates a random UUID.
@@ -10,6 +16,13 @@ impl Uuid {
     /// [`uuid::Builder::from_random_bytes`][from_random_bytes] function
     /// instead.
     ///
+    /// Reading from the operating system's RNG on every call can be slow when
+    /// generating lots of UUIDs. Enabling the `fast-rng` feature uses a
+    /// thread-local CSPRNG from the [`rand`] crate instead, which is seeded
+    /// and periodically reseeded from the operating system. [`UuidPool`]
+    /// can also be used to amortize the cost of reading from the operating
+    /// system's RNG.
+    ///
     /// Note that usage of this method requires the `v4` feature of this crate
     /// to be enabled.
     ///
@@ -29,15 +42,476 @@ impl Uuid {
     /// * [Version 4 UUIDs in RFC4122](https://www.rfc-editor.org/rfc/rfc4122#section-4.4)
     ///
     /// [`getrandom`]: https://crates.io/crates/getrandom
+    /// [`rand`]: https://crates.io/crates/rand
+    /// [`UuidPool`]: struct.UuidPool.html
     /// [from_random_bytes]: struct.Builder.html#method.from_random_bytes
     pub fn new_v4() -> Uuid {
         crate::Builder::from_random_bytes(crate::rng::bytes()).into_uuid()
     }
+
+    /// Creates an array of random UUIDs.
+    ///
+    /// The random bytes are read from the operating system's RNG for up to
+    /// 16 UUIDs at once, so this is cheaper than calling [`Uuid::new_v4`]
+    /// `N` times. The array is returned on the stack, so large values of `N`
+    /// should be avoided.
+    ///
+    /// Note that usage of this method requires the `v4` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// let uuids: [Uuid; 8] = Uuid::new_v4_array();
+    ///
+    /// assert!(uuids.iter().all(|uuid| uuid.get_version() == Some(Version::Random)));
+    /// ```
+    pub fn new_v4_array<const N: usize>() -> [Uuid; N] {
+        let mut uuids = [Uuid::nil(); N];
+        let mut bytes = [0; 16 * 16];
+
+        for chunk in uuids.chunks_mut(16) {
+            let bytes = &mut bytes[..chunk.len() * 16];
+            crate::rng::fill(bytes);
+
+            for (uuid, random_bytes) in chunk.iter_mut().zip(bytes.chunks_exact(16)) {
+                *uuid = Builder::from_random_bytes(random_bytes.try_into().unwrap()).into_uuid();
+            }
+        }
+
+        uuids
+    }
+
+    /// Creates a random UUID that isn't any of the `excluded` values, using
+    /// bytes from a caller-supplied RNG.
+    ///
+    /// This is useful when random IDs must never be mistaken for sentinel
+    /// values or existing fixtures. A new UUID is generated as if by
+    /// [`Uuid::from_rng`] until one isn't in `excluded`, up to
+    /// [`Uuid::EXCLUDED_ATTEMPTS`] times, so a seeded `rng` always gives the
+    /// same sequence of attempts. Random UUIDs are astronomically unlikely to
+    /// repeat any given value, so running out of attempts suggests `rng` is
+    /// broken.
+    ///
+    /// The nil and max UUIDs don't have the version bits of a random UUID, so
+    /// they can never be returned and don't need to be excluded.
+    ///
+    /// Note that usage of this method requires the `v4` and `rand_core`
+    /// features of this crate to be enabled.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if every UUID generated in
+    /// [`Uuid::EXCLUDED_ATTEMPTS`] attempts was in `excluded`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use rand_core::{impls, RngCore};
+    /// # use uuid::Uuid;
+    /// # struct CountingRng(u64);
+    /// # impl RngCore for CountingRng {
+    /// #     fn next_u32(&mut self) -> u32 { self.next_u64() as u32 }
+    /// #     fn next_u64(&mut self) -> u64 { self.0 += 1; self.0 }
+    /// #     fn fill_bytes(&mut self, dest: &mut [u8]) { impls::fill_bytes_via_next(self, dest) }
+    /// #     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+    /// #         self.fill_bytes(dest);
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let sentinel = Uuid::from_rng(&mut CountingRng(0));
+    ///
+    /// // The first attempt is the sentinel, so it's skipped
+    /// let uuid = Uuid::new_v4_excluding(&[sentinel], &mut CountingRng(0))?;
+    ///
+    /// assert_ne!(sentinel, uuid);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Uuid::from_rng`]: #method.from_rng
+    /// [`Uuid::EXCLUDED_ATTEMPTS`]: #associatedconstant.EXCLUDED_ATTEMPTS
+    #[cfg(feature = "rand_core")]
+    pub fn new_v4_excluding<R: rand_core::RngCore + ?Sized>(
+        excluded: &[Uuid],
+        rng: &mut R,
+    ) -> Result<Uuid, Error> {
+        new_excluding(excluded, || Uuid::from_rng(rng))
+    }
+
+    /// Checks that the RNG used by [`Uuid::new_v4`] isn't obviously broken.
+    ///
+    /// This generates `samples` random UUIDs and fails if any of them are
+    /// the same, or if any of the 122 random bits is set in a proportion of
+    /// the UUIDs that's more than 6 standard deviations away from half. That
+    /// should only happen by chance with a probability of less than one in a
+    /// million, so it can be run at startup to catch an RNG that's returning
+    /// constant or badly skewed bytes.
+    ///
+    /// This is only a smoke test. It can't tell a good RNG from a bad one
+    /// that happens to look balanced, like a counter fed through a hash, and
+    /// it's no substitute for a real statistical test suite. A few thousand
+    /// samples are enough to catch gross failures.
+    ///
+    /// Note that usage of this method requires the `v4` and `std` features
+    /// of this crate to be enabled.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a [`SelfTestError`] describing the check
+    /// that failed if a UUID was generated twice, or if a bit was biased.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::SelfTestError> {
+    /// Uuid::self_test_v4(1000)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`SelfTestError`]: enum.SelfTestError.html
+    #[cfg(feature = "std")]
+    pub fn self_test_v4(samples: usize) -> Result<(), SelfTestError> {
+        self_test(samples, Uuid::new_v4)
+    }
+
+    /// The number of UUIDs [`Uuid::new_v4_excluding`] will generate before
+    /// giving up.
+    ///
+    /// [`Uuid::new_v4_excluding`]: #method.new_v4_excluding
+    pub const EXCLUDED_ATTEMPTS: usize = 8;
+}
+
+#[cfg(feature = "rand_core")]
+fn new_excluding(excluded: &[Uuid], mut generate: impl FnMut() -> Uuid) -> Result<Uuid, Error> {
+    for _ in 0..Uuid::EXCLUDED_ATTEMPTS {
+        let uuid = generate();
+
+        if !excluded.contains(&uuid) {
+            return Ok(uuid);
+        }
+    }
+
+    Err(Error(ErrorKind::Excluded {
+        attempts: Uuid::EXCLUDED_ATTEMPTS,
+    }))
+}
+
+#[cfg(feature = "std")]
+fn self_test(samples: usize, mut generate: impl FnMut() -> Uuid) -> Result<(), SelfTestError> {
+    let mut uuids: std::vec::Vec<Uuid> = (0..samples).map(|_| generate()).collect();
+
+    uuids.sort_unstable();
+
+    if uuids.windows(2).any(|pair| pair[0] == pair[1]) {
+        return Err(SelfTestError::Repeat);
+    }
+
+    let mut ones = [0u64; 128];
+    for uuid in &uuids {
+        let value = uuid.as_u128();
+
+        for (bit, count) in ones.iter_mut().enumerate() {
+            *count += (value >> (127 - bit) & 1) as u64;
+        }
+    }
+
+    for (bit, count) in ones.iter().enumerate() {
+        // The version and variant bits are always the same
+        if (48..52).contains(&bit) || (64..66).contains(&bit) {
+            continue;
+        }
+
+        // The number of ones is binomially distributed with a standard
+        // deviation of sqrt(samples) / 2, so this checks that it's within 6
+        // standard deviations of samples / 2 without any floating point
+        let deviation = (2 * *count as i128 - samples as i128).pow(2);
+
+        if deviation > 36 * samples as i128 {
+            return Err(SelfTestError::Bias { bit });
+        }
+    }
+
+    Ok(())
+}
+
+/// The check that failed in [`Uuid::self_test_v4`].
+///
+/// Note that usage of this type requires the `v4` and `std` features of
+/// this crate to be enabled.
+///
+/// [`Uuid::self_test_v4`]: struct.Uuid.html#method.self_test_v4
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SelfTestError {
+    /// The same UUID was generated twice.
+    Repeat,
+    /// One of the random bits was set too often or too rarely.
+    ///
+    /// `bit` is the index of the bit, counting from the most significant
+    /// bit of the UUID.
+    Bias {
+        /// The index of the biased bit.
+        bit: usize,
+    },
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for SelfTestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            SelfTestError::Repeat => {
+                write!(
+                    f,
+                    "random UUID self-test failed: a UUID was generated twice"
+                )
+            }
+            SelfTestError::Bias { bit } => {
+                write!(
+                    f,
+                    "random UUID self-test failed: bit {} was too often the same",
+                    bit
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SelfTestError {}
+
+/// A pool of random UUIDs that are generated in batches.
+///
+/// The pool reads the random bytes for [`UuidPool::BATCH_SIZE`] UUIDs from
+/// the operating system's RNG at once, and hands them out one at a time.
+/// When it runs out, the next call to [`UuidPool::next`] refills it. This
+/// amortizes the cost of reading from the RNG when lots of UUIDs are
+/// generated in bursts.
+///
+/// Each UUID is built from its own 16 bytes of a single read from the same
+/// RNG used by [`Uuid::new_v4`], so UUIDs from a pool are just as random and
+/// independent as ones from [`Uuid::new_v4`]. The only difference is that the
+/// bytes for upcoming UUIDs are kept in memory until they're handed out. For
+/// that reason the pool deliberately doesn't implement `Clone`, which would
+/// hand out the same UUIDs twice.
+///
+/// The pool is `Send`, so it can be shared between threads behind a `Mutex`.
+///
+/// Note that usage of this type requires the `v4` feature of this crate
+/// to be enabled.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use uuid::{UuidPool, Version};
+/// let mut pool = UuidPool::new();
+///
+/// let uuid = pool.next();
+///
+/// assert_eq!(Some(Version::Random), uuid.get_version());
+/// ```
+///
+/// Sharing a pool between threads:
+///
+/// ```
+/// # use std::sync::{Arc, Mutex};
+/// # use uuid::UuidPool;
+/// let pool = Arc::new(Mutex::new(UuidPool::new()));
+///
+/// let handle = {
+///     let pool = pool.clone();
+///     std::thread::spawn(move || pool.lock().unwrap().next())
+/// };
+///
+/// let uuid1 = handle.join().unwrap();
+/// let uuid2 = pool.lock().unwrap().next();
+///
+/// assert_ne!(uuid1, uuid2);
+/// ```
+pub struct UuidPool {
+    bytes: [u8; UuidPool::BATCH_SIZE * 16],
+    next: usize,
+}
+
+impl UuidPool {
+    /// The number of UUIDs generated each time the pool is refilled.
+    pub const BATCH_SIZE: usize = 64;
+
+    /// Creates an empty pool.
+    ///
+    /// The pool doesn't read from the RNG until the first UUID is requested.
+    pub const fn new() -> Self {
+        UuidPool {
+            bytes: [0; UuidPool::BATCH_SIZE * 16],
+            next: UuidPool::BATCH_SIZE,
+        }
+    }
+
+    /// Returns the next random UUID in the pool, refilling it if it's empty.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Uuid {
+        if self.next == UuidPool::BATCH_SIZE {
+            crate::rng::fill(&mut self.bytes);
+            self.next = 0;
+        }
+
+        let start = self.next * 16;
+        let random_bytes = self.bytes[start..start + 16].try_into().unwrap();
+
+        // Don't keep the bytes of UUIDs that have been handed out
+        self.bytes[start..start + 16].fill(0);
+        self.next += 1;
+
+        Builder::from_random_bytes(random_bytes).into_uuid()
+    }
+
+    /// Returns the number of UUIDs left in the pool before it needs to be refilled.
+    pub const fn remaining(&self) -> usize {
+        UuidPool::BATCH_SIZE - self.next
+    }
+}
+
+impl Default for UuidPool {
+    fn default() -> Self {
+        UuidPool::new()
+    }
+}
+
+impl fmt::Debug for UuidPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Don't leak the upcoming UUIDs
+        f.debug_struct("UuidPool")
+            .field("remaining", &self.remaining())
+            .finish()
+    }
+}
+
+/// A generator for random UUIDs that rarely reads from the operating system.
+///
+/// The generator is a ChaCha20 CSPRNG that's seeded from the operating
+/// system's RNG when it's created. It's reseeded from the operating system
+/// again after every [`FastV4Generator::RESEED_INTERVAL`] bytes of output,
+/// which is every 4,096 UUIDs. In between, generating a UUID doesn't need a
+/// system call, so it's much faster than [`Uuid::new_v4`] without the
+/// `fast-rng` feature, and just as suitable for random UUIDs.
+///
+/// Each generator has its own state. It's `Send` but not `Sync`, so either
+/// keep one per thread, such as in a `thread_local!`, or share one between
+/// threads behind a `Mutex`. Like [`UuidPool`], it deliberately doesn't
+/// implement `Clone`, which would hand out the same UUIDs twice.
+///
+/// Note that usage of this type requires the `v4` and `fast-rng` features
+/// of this crate to be enabled.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use uuid::{FastV4Generator, Version};
+/// let mut generator = FastV4Generator::new();
+///
+/// let uuid = generator.next();
+///
+/// assert_eq!(Some(Version::Random), uuid.get_version());
+/// ```
+///
+/// Keeping a generator per thread:
+///
+/// ```
+/// # use std::cell::RefCell;
+/// # use uuid::{FastV4Generator, Uuid};
+/// thread_local! {
+///     static GENERATOR: RefCell<FastV4Generator> = RefCell::new(FastV4Generator::new());
+/// }
+///
+/// fn new_id() -> Uuid {
+///     GENERATOR.with(|generator| generator.borrow_mut().next())
+/// }
+///
+/// assert_ne!(new_id(), new_id());
+/// ```
+#[cfg(feature = "fast-rng")]
+pub struct FastV4Generator {
+    rng: rand::rngs::adapter::ReseedingRng<rand_chacha::ChaCha20Core, rand::rngs::OsRng>,
+}
+
+#[cfg(feature = "fast-rng")]
+impl FastV4Generator {
+    /// The number of bytes the generator produces before it's reseeded from
+    /// the operating system's RNG.
+    pub const RESEED_INTERVAL: u64 = 64 * 1024;
+
+    /// Creates a generator seeded from the operating system's RNG.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the operating system's RNG can't be read.
+    pub fn new() -> Self {
+        use rand::SeedableRng;
+
+        let core = rand_chacha::ChaCha20Core::from_rng(rand::rngs::OsRng).unwrap_or_else(|err| {
+            // NB: rand::Error has no source; this is adequate display
+            panic!("could not retrieve random bytes for uuid: {}", err)
+        });
+
+        FastV4Generator {
+            rng: rand::rngs::adapter::ReseedingRng::new(
+                core,
+                FastV4Generator::RESEED_INTERVAL,
+                rand::rngs::OsRng,
+            ),
+        }
+    }
+
+    /// Returns a new random UUID.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the generator needs to be reseeded and the
+    /// operating system's RNG can't be read.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Uuid {
+        let mut bytes = [0; 16];
+        rand::RngCore::fill_bytes(&mut self.rng, &mut bytes);
+
+        Builder::from_random_bytes(bytes).into_uuid()
+    }
+}
+
+#[cfg(feature = "fast-rng")]
+impl Default for FastV4Generator {
+    fn default() -> Self {
+        FastV4Generator::new()
+    }
+}
+
+#[cfg(feature = "fast-rng")]
+impl fmt::Debug for FastV4Generator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Don't leak the state of the generator
+        f.debug_struct("FastV4Generator").finish_non_exhaustive()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "rand_core")]
+    use crate::external::rand_core_support::tests::StepRng;
     use crate::{Variant, Version};
 
     #[cfg(target_arch = "wasm32")]
@@ -52,6 +526,29 @@ mod tests {
         assert_eq!(uuid.get_variant(), Variant::RFC4122);
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_many() {
+        for _ in 0..1000 {
+            let uuid = Uuid::new_v4();
+
+            // The version is the high nibble of byte 6, the variant the top two bits of byte 8
+            assert_eq!(0x40, uuid.as_bytes()[6] & 0xf0);
+            assert_eq!(0x80, uuid.as_bytes()[8] & 0xc0);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_from_random_bytes() {
+        for bytes in [[0; 16], [0xff; 16], [0xa5; 16]] {
+            let uuid = Builder::from_random_bytes(bytes).into_uuid();
+
+            assert_eq!(Some(Version::Random), uuid.get_version());
+            assert_eq!(Variant::RFC4122, uuid.get_variant());
+        }
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn test_get_version() {
@@ -60,4 +557,229 @@ mod tests {
         assert_eq!(uuid.get_version(), Some(Version::Random));
         assert_eq!(uuid.get_version_num(), 4)
     }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_array_empty() {
+        let uuids: [Uuid; 0] = Uuid::new_v4_array();
+
+        assert!(uuids.is_empty());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_array() {
+        let mut uuids: [Uuid; 100] = Uuid::new_v4_array();
+
+        for uuid in uuids.iter() {
+            assert_eq!(uuid.get_version(), Some(Version::Random));
+            assert_eq!(uuid.get_variant(), Variant::RFC4122);
+        }
+
+        uuids.sort();
+        assert!(uuids.windows(2).all(|pair| pair[0] != pair[1]));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(feature = "rand_core")]
+    fn test_new_excluding() {
+        let excluded = Uuid::new_v4();
+
+        let uuid = Uuid::new_v4_excluding(&[excluded, Uuid::nil()], &mut StepRng(0)).unwrap();
+
+        assert_eq!(uuid.get_version(), Some(Version::Random));
+        assert_ne!(uuid, excluded);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(feature = "rand_core")]
+    fn test_new_excluding_is_deterministic() {
+        // Exclude the first two UUIDs the RNG produces
+        let mut rng = StepRng(0);
+        let excluded = [Uuid::from_rng(&mut rng), Uuid::from_rng(&mut rng)];
+        let expected = Uuid::from_rng(&mut rng);
+
+        let mut rng = StepRng(0);
+
+        assert_eq!(Uuid::new_v4_excluding(&excluded, &mut rng), Ok(expected));
+        assert_eq!(rng.0, 48);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(feature = "rand_core")]
+    fn test_new_excluding_retries() {
+        let excluded = [Uuid::new_v4(), Uuid::new_v4()];
+        let allowed = Uuid::new_v4();
+
+        // Generate each excluded UUID before one that's allowed
+        let mut generated = excluded.iter().chain(Some(&allowed)).copied();
+
+        let uuid = new_excluding(&excluded, || generated.next().unwrap()).unwrap();
+
+        assert_eq!(uuid, allowed);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(feature = "rand_core")]
+    fn test_new_excluding_gives_up() {
+        let excluded = Uuid::new_v4();
+        let mut attempts = 0;
+
+        let err = new_excluding(&[excluded], || {
+            attempts += 1;
+            excluded
+        })
+        .unwrap_err();
+
+        assert_eq!(attempts, Uuid::EXCLUDED_ATTEMPTS);
+        assert_eq!(
+            err,
+            Error(ErrorKind::Excluded {
+                attempts: Uuid::EXCLUDED_ATTEMPTS
+            })
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(feature = "std")]
+    fn test_self_test() {
+        assert_eq!(Uuid::self_test_v4(0), Ok(()));
+        assert_eq!(Uuid::self_test_v4(1), Ok(()));
+        assert_eq!(Uuid::self_test_v4(4096), Ok(()));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(feature = "std")]
+    fn test_self_test_constant() {
+        let constant = Uuid::new_v4();
+
+        assert_eq!(self_test(100, || constant), Err(SelfTestError::Repeat));
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(feature = "std")]
+    fn test_self_test_biased() {
+        use crate::std::string::ToString;
+
+        // Every UUID is different, but the high bits are always zero
+        let mut counter = 0u128;
+
+        let err = self_test(1000, || {
+            counter += 1;
+
+            Builder::from_random_bytes(counter.to_be_bytes()).into_uuid()
+        });
+
+        assert_eq!(err, Err(SelfTestError::Bias { bit: 0 }));
+
+        // Random bytes with a bit stuck on
+        let mut rng = crate::tests::xorshift(1);
+
+        let err = self_test(1000, || {
+            let mut bytes = rng();
+            bytes[15] |= 1;
+
+            Builder::from_random_bytes(bytes).into_uuid()
+        });
+
+        assert_eq!(err, Err(SelfTestError::Bias { bit: 127 }));
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "random UUID self-test failed: bit 127 was too often the same"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_pool() {
+        let mut pool = UuidPool::new();
+        assert_eq!(pool.remaining(), 0);
+
+        let uuid = pool.next();
+
+        assert_eq!(uuid.get_version(), Some(Version::Random));
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+        assert_eq!(pool.remaining(), UuidPool::BATCH_SIZE - 1);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_pool_refill() {
+        use crate::std::vec::Vec;
+
+        let mut pool = UuidPool::new();
+
+        let mut uuids: Vec<Uuid> = (0..UuidPool::BATCH_SIZE * 3 + 1)
+            .map(|_| {
+                let uuid = pool.next();
+                assert_eq!(uuid.get_version(), Some(Version::Random));
+                uuid
+            })
+            .collect();
+
+        assert_eq!(pool.remaining(), UuidPool::BATCH_SIZE - 1);
+
+        // None of the UUIDs were repeated across refills
+        uuids.sort();
+        uuids.dedup();
+        assert_eq!(uuids.len(), UuidPool::BATCH_SIZE * 3 + 1);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_pool_debug() {
+        use crate::std::format;
+
+        let mut pool = UuidPool::new();
+        pool.next();
+
+        assert_eq!(format!("{:?}", pool), "UuidPool { remaining: 63 }");
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(feature = "fast-rng")]
+    fn test_fast_generator() {
+        use crate::std::vec::Vec;
+
+        let mut generator = FastV4Generator::new();
+
+        // Enough UUIDs for the generator to be reseeded twice
+        let count = (FastV4Generator::RESEED_INTERVAL / 16 * 2 + 1) as usize;
+
+        let mut uuids: Vec<Uuid> = (0..count)
+            .map(|_| {
+                let uuid = generator.next();
+                assert_eq!(uuid.get_version(), Some(Version::Random));
+                assert_eq!(uuid.get_variant(), Variant::RFC4122);
+                uuid
+            })
+            .collect();
+
+        uuids.sort();
+        uuids.dedup();
+        assert_eq!(uuids.len(), count);
+
+        // Separate generators are seeded separately
+        assert_ne!(FastV4Generator::new().next(), FastV4Generator::new().next());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(feature = "fast-rng")]
+    fn test_fast_generator_debug() {
+        use crate::std::format;
+
+        assert_eq!(
+            format!("{:?}", FastV4Generator::new()),
+            "FastV4Generator { .. }"
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use crate::Uuid;
+use crate::{std::fmt, Uuid};
 
 impl Uuid {
     /// Creates a UUID using a name from a namespace, based on the SHA-1 hash.
@@ -24,6 +24,19 @@ impl Uuid {
     /// assert_eq!(Some(Version::Sha1), uuid.get_version());
     /// ```
     ///
+    /// The UUID for a name is always the same, so it can be checked against
+    /// other implementations:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"example.org");
+    ///
+    /// assert_eq!(
+    ///     "aad03681-8b63-5304-89e0-8ca8f49461b5",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    ///
     /// # References
     ///
     /// * [Version 3 and 5 UUIDs in RFC4122](https://www.rfc-editor.org/rfc/rfc4122#section-4.3)
@@ -35,6 +48,157 @@ impl Uuid {
     pub fn new_v5(namespace: &Uuid, name: &[u8]) -> Uuid {
         crate::Builder::from_sha1_bytes(crate::sha1::hash(namespace.as_bytes(), name)).into_uuid()
     }
+
+    /// Checks whether this UUID is the version 5 UUID of a name in a namespace.
+    ///
+    /// This recomputes [`Uuid::new_v5`] for `namespace` and `name` and
+    /// compares the result with this UUID. It can be used to detect stored
+    /// UUIDs that don't match the name they're supposed to be derived from.
+    ///
+    /// Note that usage of this method requires the `v5` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"rust-lang.org");
+    ///
+    /// assert!(uuid.is_v5_of(&Uuid::NAMESPACE_DNS, b"rust-lang.org"));
+    /// assert!(!uuid.is_v5_of(&Uuid::NAMESPACE_DNS, b"crates.io"));
+    /// ```
+    ///
+    /// [`Uuid::new_v5`]: #method.new_v5
+    pub fn is_v5_of(&self, namespace: &Uuid, name: &[u8]) -> bool {
+        *self == Uuid::new_v5(namespace, name)
+    }
+
+    /// Searches for the namespace and name this version 5 UUID was created
+    /// from.
+    ///
+    /// Every combination of the given `namespaces` and `names` is tried
+    /// with [`Uuid::is_v5_of`], and the first one that matches is returned.
+    /// Since version 5 UUIDs are based on a one-way hash this is the only
+    /// way to recover where they came from, so it's only practical when the
+    /// candidates are already known, like when debugging deterministic IDs.
+    ///
+    /// If this UUID isn't a version 5 UUID then `None` is returned without
+    /// hashing anything.
+    ///
+    /// The matching name is returned as a slice borrowed from `names` rather
+    /// than an owned copy. It's always one of the candidates passed in, so
+    /// there's nothing to allocate, and this works without the `std` feature.
+    /// Call `to_vec` on it if it needs to outlive the candidates.
+    ///
+    /// Note that usage of this method requires the `v5` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let uuid = Uuid::new_v5(&Uuid::NAMESPACE_URL, b"rust-lang.org");
+    ///
+    /// let namespaces = [Uuid::NAMESPACE_DNS, Uuid::NAMESPACE_URL];
+    /// let names: [&[u8]; 2] = [b"crates.io", b"rust-lang.org"];
+    ///
+    /// assert_eq!(
+    ///     Some((Uuid::NAMESPACE_URL, &b"rust-lang.org"[..])),
+    ///     uuid.derived_from(&namespaces, &names),
+    /// );
+    /// ```
+    ///
+    /// [`Uuid::is_v5_of`]: #method.is_v5_of
+    pub fn derived_from<'a>(
+        &self,
+        namespaces: &[Uuid],
+        names: &[&'a [u8]],
+    ) -> Option<(Uuid, &'a [u8])> {
+        if self.get_version() != Some(crate::Version::Sha1) {
+            return None;
+        }
+
+        for namespace in namespaces {
+            for &name in names {
+                if self.is_v5_of(namespace, name) {
+                    return Some((*namespace, name));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Creates a [`V5Hasher`] that builds a version 5 UUID from a name
+    /// in a namespace that's fed in as a sequence of chunks.
+    ///
+    /// This produces the same UUID as [`Uuid::new_v5`] on the chunks joined
+    /// together, without needing to buffer the whole name first. It's useful
+    /// for deriving a UUID from a large input like the contents of a file.
+    ///
+    /// Note that usage of this method requires the `v5` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// let mut hasher = Uuid::v5_hasher(&Uuid::NAMESPACE_DNS);
+    ///
+    /// hasher.update(b"rust-");
+    /// hasher.update(b"lang.org");
+    ///
+    /// assert_eq!(
+    ///     Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"rust-lang.org"),
+    ///     hasher.finalize(),
+    /// );
+    /// ```
+    ///
+    /// [`V5Hasher`]: struct.V5Hasher.html
+    /// [`Uuid::new_v5`]: #method.new_v5
+    pub fn v5_hasher(namespace: &Uuid) -> V5Hasher {
+        V5Hasher(crate::sha1::Hasher::new(namespace.as_bytes()))
+    }
+}
+
+/// An incremental builder for version 5 UUIDs, based on the SHA-1 hash.
+///
+/// This is created by [`Uuid::v5_hasher`]. The namespace is hashed
+/// first, then each chunk of the name passed to
+/// [`V5Hasher::update`], in order. Calling [`V5Hasher::finalize`] sets the
+/// version and variant and returns the UUID.
+///
+/// Note that usage of this type requires the `v5` feature of this crate
+/// to be enabled.
+///
+/// [`Uuid::v5_hasher`]: struct.Uuid.html#method.v5_hasher
+/// [`V5Hasher::update`]: #method.update
+/// [`V5Hasher::finalize`]: #method.finalize
+#[derive(Clone)]
+pub struct V5Hasher(crate::sha1::Hasher);
+
+impl V5Hasher {
+    /// Feeds the next chunk of the name into the hash.
+    pub fn update(&mut self, chunk: &[u8]) {
+        self.0.update(chunk);
+    }
+
+    /// Finishes hashing the name and returns the version 5 UUID.
+    pub fn finalize(self) -> Uuid {
+        crate::Builder::from_sha1_bytes(self.0.finalize()).into_uuid()
+    }
+}
+
+impl fmt::Debug for V5Hasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("V5Hasher").finish_non_exhaustive()
+    }
 }
 
 #[cfg(test)]
@@ -46,7 +210,7 @@ mod tests {
 
     use crate::{std::string::ToString, Variant, Version};
 
-    static FIXTURE: &'static [(&'static Uuid, &'static str, &'static str)] = &[
+    static FIXTURE: &[(&Uuid, &str, &str)] = &[
         (
             &Uuid::NAMESPACE_DNS,
             "example.org",
@@ -141,8 +305,8 @@ mod tests {
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn test_hyphenated() {
-        for &(ref ns, ref name, ref expected) in FIXTURE {
-            let uuid = Uuid::new_v5(*ns, name.as_bytes());
+        for (ns, name, expected) in FIXTURE {
+            let uuid = Uuid::new_v5(ns, name.as_bytes());
 
             assert_eq!(uuid.hyphenated().to_string(), *expected)
         }
@@ -151,12 +315,82 @@ mod tests {
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn test_new() {
-        for &(ref ns, ref name, ref u) in FIXTURE {
-            let uuid = Uuid::new_v5(*ns, name.as_bytes());
+        for (ns, name, u) in FIXTURE {
+            let uuid = Uuid::new_v5(ns, name.as_bytes());
 
             assert_eq!(uuid.get_version(), Some(Version::Sha1));
             assert_eq!(uuid.get_variant(), Variant::RFC4122);
             assert_eq!(Ok(uuid), u.parse());
         }
     }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_is_v5_of() {
+        for (ns, name, u) in FIXTURE {
+            let uuid: Uuid = u.parse().unwrap();
+
+            assert!(uuid.is_v5_of(ns, name.as_bytes()));
+            assert!(!uuid.is_v5_of(ns, b"not the name"));
+            assert!(!uuid.is_v5_of(&Uuid::nil(), name.as_bytes()));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_derived_from() {
+        let namespaces = [
+            Uuid::NAMESPACE_DNS,
+            Uuid::NAMESPACE_URL,
+            Uuid::NAMESPACE_OID,
+            Uuid::NAMESPACE_X500,
+        ];
+        let names: [&[u8]; 4] = [b"example.org", b"rust-lang.org", b"42", b"lorem ipsum"];
+
+        for (ns, name, u) in FIXTURE {
+            let uuid: Uuid = u.parse().unwrap();
+
+            assert_eq!(
+                uuid.derived_from(&namespaces, &names),
+                Some((**ns, name.as_bytes()))
+            );
+            assert_eq!(uuid.derived_from(&namespaces, &[b"not the name"]), None);
+            assert_eq!(uuid.derived_from(&[], &names), None);
+        }
+
+        let not_v5 = Uuid::from_bytes([0x42; 16]);
+
+        assert_eq!(not_v5.derived_from(&namespaces, &names), None);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_hasher() {
+        for (ns, name, u) in FIXTURE {
+            let expected: Uuid = u.parse().unwrap();
+
+            // Every way of splitting the name into two chunks
+            for split in 0..=name.len() {
+                let mut hasher = Uuid::v5_hasher(ns);
+
+                hasher.update(&name.as_bytes()[..split]);
+                hasher.update(&name.as_bytes()[split..]);
+
+                assert_eq!(hasher.finalize(), expected);
+            }
+
+            let mut hasher = Uuid::v5_hasher(ns);
+            for byte in name.as_bytes() {
+                hasher.update(&[*byte]);
+            }
+
+            assert_eq!(hasher.clone().finalize(), expected);
+            assert_eq!(hasher.finalize(), Uuid::new_v5(ns, name.as_bytes()));
+        }
+
+        assert_eq!(
+            Uuid::v5_hasher(&Uuid::NAMESPACE_DNS).finalize(),
+            Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"")
+        );
+    }
 }
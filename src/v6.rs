@@ -22,6 +22,42 @@ impl Uuid {
         Self::new_v6(ts, node_id)
     }
 
+    /// Create a new version 6 UUID using the current system time and a
+    /// random node ID.
+    ///
+    /// This is like [`Uuid::now_v6`], but for when there's no suitable node
+    /// ID, or a hardware address shouldn't be revealed. The node ID is a
+    /// random 48bit value with the multicast bit set, so it can't collide
+    /// with a real IEEE 802 MAC address. It's generated the first time it's
+    /// needed and then reused for the lifetime of the process, so all UUIDs
+    /// created by a process share the same node ID, but it will be different
+    /// each time the process is restarted.
+    ///
+    /// The clock sequence is shared with [`Uuid::now_v1`] and
+    /// [`Uuid::now_v6`].
+    ///
+    /// Note that usage of this method requires the `v6`, `std`, and `rng` features of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// let uuid1 = Uuid::new_v6_now();
+    /// let uuid2 = Uuid::new_v6_now();
+    ///
+    /// assert_eq!(Some(Version::SortMac), uuid1.get_version());
+    /// assert_eq!(uuid1.as_bytes()[10..], uuid2.as_bytes()[10..]);
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [Node ID in RFC4122](https://www.rfc-editor.org/rfc/rfc4122#section-4.5)
+    #[cfg(all(feature = "std", feature = "rng"))]
+    pub fn new_v6_now() -> Self {
+        Self::now_v6(&process_node_id())
+    }
+
     /// Create a new version 6 UUID using the given timestamp and a node ID.
     ///
     /// This is similar to version 1 UUIDs, except that it is lexicographically sortable by timestamp.
@@ -96,6 +132,41 @@ impl Uuid {
     }
 }
 
+/// Get a random node ID that's stable for the lifetime of the process.
+#[cfg(all(feature = "std", feature = "rng"))]
+fn process_node_id() -> [u8; 6] {
+    use crate::std::sync::{
+        atomic::{AtomicU32, Ordering},
+        Once,
+    };
+
+    // The node ID is split over two 32bit atomics so this works on targets
+    // without 64bit atomics. `Once` makes the stores visible to every thread
+    // that returns from `call_once`, so relaxed loads are enough
+    static INIT: Once = Once::new();
+    static HIGH: AtomicU32 = AtomicU32::new(0);
+    static LOW: AtomicU32 = AtomicU32::new(0);
+
+    INIT.call_once(|| {
+        let mut node_id = crate::rng::node_id();
+        node_id[0] |= 0x01;
+
+        HIGH.store(
+            u32::from_be_bytes([node_id[0], node_id[1], node_id[2], node_id[3]]),
+            Ordering::Relaxed,
+        );
+        LOW.store(
+            u32::from_be_bytes([0, 0, node_id[4], node_id[5]]),
+            Ordering::Relaxed,
+        );
+    });
+
+    let high = HIGH.load(Ordering::Relaxed).to_be_bytes();
+    let low = LOW.load(Ordering::Relaxed).to_be_bytes();
+
+    [high[0], high[1], high[2], high[3], low[2], low[3]]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -147,6 +218,37 @@ mod tests {
         assert_eq!(uuid.get_variant(), Variant::RFC4122);
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(all(feature = "std", feature = "rng"))]
+    fn test_new_now() {
+        let uuid1 = Uuid::new_v6_now();
+        let uuid2 = Uuid::new_v6_now();
+
+        assert_eq!(uuid1.get_version(), Some(Version::SortMac));
+        assert_eq!(uuid1.get_variant(), Variant::RFC4122);
+
+        assert_ne!(uuid1, uuid2);
+        assert_eq!(uuid1.as_bytes()[10..], uuid2.as_bytes()[10..]);
+
+        // The multicast bit is set
+        assert_eq!(uuid1.as_bytes()[10] & 0x01, 0x01);
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "rng", not(target_arch = "wasm32")))]
+    fn test_new_now_threads() {
+        use std::{thread, vec::Vec};
+
+        let node_id = process_node_id();
+
+        let handles: Vec<_> = (0..4).map(|_| thread::spawn(Uuid::new_v6_now)).collect();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap().as_bytes()[10..], node_id);
+        }
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn test_new_context() {
@@ -3,7 +3,20 @@
 //! Note that you need to enable the `v7` Cargo feature
 //! in order to use this module.
 
-use crate::{std::convert::TryInto, rng, timestamp::Timestamp, Builder, Uuid};
+use crate::{
+    error::{Error, ErrorKind},
+    rng,
+    std::convert::TryInto,
+    timestamp::{Clock, Timestamp},
+    Builder, Uuid, Variant, Version,
+};
+
+#[cfg(target_has_atomic = "64")]
+use crate::std::sync::atomic::{AtomicU64, Ordering};
+
+/// The number of bits after the timestamp of a version 7 UUID that can
+/// hold a counter or random data.
+const COUNTER_BITS_MAX: u8 = 74;
 
 impl Uuid {
     /// Create a new version 7 UUID using the current time value and random bytes.
@@ -12,7 +25,47 @@ impl Uuid {
     /// as the source timestamp.
     #[cfg(feature = "std")]
     pub fn now_v7() -> Self {
-        Self::new_v7(Timestamp::now(crate::NoContext))
+        Self::new_v7_with_clock(&crate::timestamp::SystemClock)
+    }
+
+    /// Create a new version 7 UUID using the time from a [`Clock`] and
+    /// random bytes.
+    ///
+    /// The `clock` is read exactly once. Substituting a fixed or stepping
+    /// clock for the system time makes the timestamps of generated UUIDs
+    /// reproducible, though the rest of the UUID is still random.
+    ///
+    /// Note that usage of this method requires the `v7` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// Using a fixed clock:
+    ///
+    /// ```rust
+    /// # use uuid::{timestamp::Clock, Uuid};
+    /// struct FixedClock(u64);
+    ///
+    /// impl Clock for FixedClock {
+    ///     fn now_millis(&self) -> u64 {
+    ///         self.0
+    ///     }
+    /// }
+    ///
+    /// let uuid = Uuid::new_v7_with_clock(&FixedClock(1497624119000));
+    ///
+    /// assert!(
+    ///     uuid.hyphenated().to_string().starts_with("015cb15a-86d8-7")
+    /// );
+    /// ```
+    ///
+    /// [`Clock`]: timestamp/trait.Clock.html
+    pub fn new_v7_with_clock<C: Clock + ?Sized>(clock: &C) -> Self {
+        Builder::from_unix_timestamp_millis(
+            clock.now_millis(),
+            &rng::bytes()[..10].try_into().unwrap(),
+        )
+        .into_uuid()
     }
 
     /// Create a new version 7 UUID using a time value and random bytes.
@@ -51,11 +104,428 @@ impl Uuid {
         Builder::from_unix_timestamp_millis(millis, &rng::bytes()[..10].try_into().unwrap())
             .into_uuid()
     }
+
+    /// Create a new version 7 UUID using a time value and random bytes taken
+    /// from the front of a recorded pool.
+    ///
+    /// The 10 random bytes that [`Uuid::new_v7`] would read from the RNG are
+    /// taken from the start of `pool` instead, and `pool` is advanced past
+    /// them. Replaying the same pool with the same timestamps reproduces the
+    /// exact same sequence of UUIDs, which is useful for deterministic tests
+    /// that still go through the same layout as random UUIDs.
+    ///
+    /// Note that usage of this method requires the `v7` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if there are fewer than 10 bytes
+    /// left in `pool`. The pool isn't advanced in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use uuid::{Uuid, Timestamp, NoContext};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let recorded = [0x42; 20];
+    /// let mut pool = &recorded[..];
+    ///
+    /// let ts = Timestamp::from_unix(NoContext, 1497624119, 1234);
+    ///
+    /// let uuid = Uuid::new_v7_replay(ts, &mut pool)?;
+    ///
+    /// assert_eq!("015cb15a-86d8-7242-8242-424242424242", uuid.to_string());
+    /// assert_eq!(10, pool.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Uuid::new_v7`]: #method.new_v7
+    pub fn new_v7_replay(ts: Timestamp, pool: &mut &[u8]) -> Result<Self, Error> {
+        if pool.len() < 10 {
+            return Err(Error(ErrorKind::PoolExhausted {
+                remaining: pool.len(),
+            }));
+        }
+
+        let (random_bytes, rest) = pool.split_at(10);
+        *pool = rest;
+
+        let (secs, nanos) = ts.to_unix();
+        let millis = (secs * 1000).saturating_add(nanos as u64 / 1_000_000);
+
+        Ok(
+            Builder::from_unix_timestamp_millis(millis, random_bytes.try_into().unwrap())
+                .into_uuid(),
+        )
+    }
+
+    /// Create a new version 7 UUID using a time value, a shared counter, and
+    /// random bytes from a caller-supplied RNG.
+    ///
+    /// The `counter` makes every UUID created with it strictly greater than
+    /// the last, even when the time value doesn't change or goes backwards.
+    /// It can be shared between threads, so all the UUIDs generated on a
+    /// node are ordered without any other coordination.
+    ///
+    /// The counter holds the Unix timestamp in milliseconds of the last UUID
+    /// it was used for, shifted left by 12 bits, plus a 12 bit sequence
+    /// number that's stored in the `rand_a` field of the UUID. A new counter
+    /// should start at zero. Its value can be saved and used to create the
+    /// counter again later, such as after a restart, to keep new UUIDs
+    /// ordered after the ones already generated.
+    ///
+    /// The remaining 62 bits of the UUID are read from `rng`, so a seeded
+    /// RNG gives the same UUIDs for the same timestamps and counter values.
+    ///
+    /// The counter never wraps around or reseeds. If more than 4096 UUIDs
+    /// are generated within the same millisecond then the sequence number
+    /// spills over into the timestamp, so those UUIDs have a timestamp ahead
+    /// of `ts` until the time catches up. Once the counter reaches
+    /// `u64::MAX` it saturates instead, and UUIDs stop being strictly
+    /// increasing, but that's far past the year 10,000.
+    ///
+    /// Note that usage of this method requires the `v7` and `rand_core`
+    /// features of this crate to be enabled, and a target with 64 bit atomics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::sync::atomic::AtomicU64;
+    /// # use uuid::{Uuid, Timestamp, NoContext};
+    /// # use rand_core::{impls, RngCore};
+    /// # struct CountingRng(u64);
+    /// # impl RngCore for CountingRng {
+    /// #     fn next_u32(&mut self) -> u32 { self.next_u64() as u32 }
+    /// #     fn next_u64(&mut self) -> u64 { self.0 += 1; self.0 }
+    /// #     fn fill_bytes(&mut self, dest: &mut [u8]) { impls::fill_bytes_via_next(self, dest) }
+    /// #     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+    /// #         self.fill_bytes(dest);
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// // Any `RngCore` works, such as a seeded generator in tests
+    /// let mut rng = CountingRng(0);
+    /// let counter = AtomicU64::new(0);
+    ///
+    /// let ts = Timestamp::from_unix(NoContext, 1497624119, 1234);
+    ///
+    /// let uuid1 = Uuid::new_v7_with_counter(ts, &counter, &mut rng);
+    /// let uuid2 = Uuid::new_v7_with_counter(ts, &counter, &mut rng);
+    ///
+    /// assert!(uuid1 < uuid2);
+    /// assert!(uuid1.hyphenated().to_string().starts_with("015cb15a-86d8-7000-"));
+    /// assert!(uuid2.hyphenated().to_string().starts_with("015cb15a-86d8-7001-"));
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [Monotonicity and Counters in Draft RFC: New UUID Formats, Version 4](https://datatracker.ietf.org/doc/html/draft-peabody-dispatch-new-uuid-format-04#section-6.2)
+    #[cfg(all(feature = "rand_core", target_has_atomic = "64"))]
+    pub fn new_v7_with_counter<R: rand_core::RngCore + ?Sized>(
+        ts: Timestamp,
+        counter: &AtomicU64,
+        rng: &mut R,
+    ) -> Self {
+        let (secs, nanos) = ts.to_unix();
+        let millis = (secs * 1000).saturating_add(nanos as u64 / 1_000_000);
+
+        let mut random_bytes = [0; 10];
+        rng.fill_bytes(&mut random_bytes);
+
+        next_with_counter(millis << 12, counter, random_bytes)
+    }
+
+    /// Create a new version 7 UUID using a time value with sub-millisecond
+    /// precision, a shared counter, and random bytes.
+    ///
+    /// This is like [`Uuid::new_v7_with_counter`], but the 12 bit `rand_a`
+    /// field starts from the fraction of the millisecond in `ts` scaled to 12
+    /// bits, instead of from zero. UUIDs created in the same millisecond are
+    /// then ordered by their nanosecond timestamps as well as by the order
+    /// they were created in.
+    ///
+    /// The fraction is only precise to 1/4096th of a millisecond, or about
+    /// 244ns. When two UUIDs fall into the same fraction, or the time value
+    /// goes backwards, the counter is incremented past the last value instead,
+    /// which can move the fraction, and eventually the timestamp, ahead of
+    /// `ts`. Ordering by time is only as good as the precision and
+    /// monotonicity of the clock the timestamps come from.
+    ///
+    /// The same `counter` should not be shared with
+    /// [`Uuid::new_v7_with_counter`], which interprets `rand_a` as a plain
+    /// sequence number.
+    ///
+    /// Note that usage of this method requires the `v7` feature of this crate
+    /// to be enabled, and a target with 64 bit atomics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::sync::atomic::AtomicU64;
+    /// # use uuid::{Uuid, Timestamp, NoContext};
+    /// let counter = AtomicU64::new(0);
+    ///
+    /// let uuid1 = Uuid::new_v7_high_res(Timestamp::from_unix(NoContext, 1497624119, 500_000), &counter);
+    /// let uuid2 = Uuid::new_v7_high_res(Timestamp::from_unix(NoContext, 1497624119, 250_000), &counter);
+    ///
+    /// assert!(uuid1.hyphenated().to_string().starts_with("015cb15a-86d8-7800-"));
+    ///
+    /// // Time went backwards, so the counter was used instead
+    /// assert!(uuid1 < uuid2);
+    /// assert!(uuid2.hyphenated().to_string().starts_with("015cb15a-86d8-7801-"));
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [Monotonicity and Counters in Draft RFC: New UUID Formats, Version 4](https://datatracker.ietf.org/doc/html/draft-peabody-dispatch-new-uuid-format-04#section-6.2)
+    #[cfg(target_has_atomic = "64")]
+    pub fn new_v7_high_res(ts: Timestamp, counter: &AtomicU64) -> Self {
+        let (secs, nanos) = ts.to_unix();
+        let millis = (secs * 1000).saturating_add(nanos as u64 / 1_000_000);
+
+        let fraction = (nanos as u64 % 1_000_000) * 4096 / 1_000_000;
+
+        next_with_counter(
+            millis << 12 | fraction,
+            counter,
+            rng::bytes()[..10].try_into().unwrap(),
+        )
+    }
+
+    /// Create a new version 7 UUID using a time value, the last UUID
+    /// generated, a counter of `bits` bits, and random bytes.
+    ///
+    /// This is method 2 of the monotonic counters described for version 7
+    /// UUIDs: the `bits` bits after the 48 bit timestamp hold a counter, and
+    /// the remaining `74 - bits` bits are filled from `random_bytes`. The
+    /// counter starts from zero in each millisecond, and is incremented past
+    /// the counter in `last` when the time value is the same as, or before,
+    /// the timestamp in `last`. Wider counters allow more UUIDs to be
+    /// generated within a single millisecond before the counter overflows
+    /// into the timestamp, at the cost of fewer random bits.
+    ///
+    /// `last` is updated to the new UUID. It should start as [`Uuid::nil`].
+    /// Since the counter is read from `last` itself, `bits` can be changed
+    /// between calls without breaking the ordering.
+    ///
+    /// Note that usage of this method requires the `v7` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `bits` is more than 74.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use uuid::{Uuid, Timestamp, NoContext};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let mut last = Uuid::nil();
+    ///
+    /// let ts = Timestamp::from_unix(NoContext, 1497624119, 1234);
+    ///
+    /// let uuid1 = Uuid::new_v7_counter_bits(ts, &mut last, 42, &[0xff; 10])?;
+    /// let uuid2 = Uuid::new_v7_counter_bits(ts, &mut last, 42, &[0x00; 10])?;
+    ///
+    /// assert!(uuid1 < uuid2);
+    /// assert_eq!(uuid2, last);
+    /// assert_eq!("015cb15a-86d8-7000-8000-0000ffffffff", uuid1.to_string());
+    /// assert_eq!("015cb15a-86d8-7000-8000-000100000000", uuid2.to_string());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # References
+    ///
+    /// * [Monotonicity and Counters in Draft RFC: New UUID Formats, Version 4](https://datatracker.ietf.org/doc/html/draft-peabody-dispatch-new-uuid-format-04#section-6.2)
+    ///
+    /// [`Uuid::nil`]: #method.nil
+    pub fn new_v7_counter_bits(
+        ts: Timestamp,
+        last: &mut Uuid,
+        bits: u8,
+        random_bytes: &[u8; 10],
+    ) -> Result<Self, Error> {
+        if bits > COUNTER_BITS_MAX {
+            return Err(Error(ErrorKind::CounterBits { bits }));
+        }
+
+        let (secs, nanos) = ts.to_unix();
+        let millis = (secs * 1000).saturating_add(nanos as u64 / 1_000_000);
+
+        let random_bits = COUNTER_BITS_MAX - bits;
+
+        // The timestamp and counter together, with the counter at zero
+        let floor = ((millis & 0xffff_ffff_ffff) as u128) << bits;
+        let prefix = (unpack_payload(*last) >> random_bits)
+            .saturating_add(1)
+            .max(floor);
+
+        let mut random = [0; 16];
+        random[6..].copy_from_slice(random_bytes);
+        let random = u128::from_be_bytes(random) & ((1 << random_bits) - 1);
+
+        let uuid = pack_payload(prefix << random_bits | random);
+        *last = uuid;
+
+        Ok(uuid)
+    }
+
+    /// Create a batch of version 7 UUIDs using the current time value, each
+    /// paired with the Unix timestamp in milliseconds it contains.
+    ///
+    /// The clock is only read once for the whole batch. Each UUID is then
+    /// created as if by [`Uuid::new_v7_with_counter`] with a counter that's
+    /// private to the batch, so the UUIDs are strictly increasing in the
+    /// order they're returned. The first 4096 UUIDs share the same timestamp.
+    /// If `n` is larger than that then the counter carries into the
+    /// timestamp, so later UUIDs will have timestamps slightly ahead of the
+    /// current time.
+    ///
+    /// The timestamps are the ones encoded into each UUID, so they're always
+    /// equal to the result of [`Uuid::get_timestamp`].
+    ///
+    /// Note that usage of this method requires the `v7` and `std` features of
+    /// this crate to be enabled, and a target with 64 bit atomics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use uuid::Uuid;
+    /// let batch = Uuid::now_v7_batch(3);
+    ///
+    /// assert_eq!(3, batch.len());
+    /// assert!(batch[0].0 < batch[1].0 && batch[1].0 < batch[2].0);
+    /// assert_eq!(batch[0].1, batch[2].1);
+    /// ```
+    ///
+    /// [`Uuid::get_timestamp`]: #method.get_timestamp
+    #[cfg(all(feature = "std", target_has_atomic = "64"))]
+    pub fn now_v7_batch(n: usize) -> std::vec::Vec<(Self, u64)> {
+        let (secs, nanos) = Timestamp::now(crate::NoContext).to_unix();
+        let millis = (secs * 1000).saturating_add(nanos as u64 / 1_000_000);
+
+        let counter = AtomicU64::new(0);
+
+        (0..n)
+            .map(|_| {
+                let uuid = next_with_counter(
+                    millis << 12,
+                    &counter,
+                    rng::bytes()[..10].try_into().unwrap(),
+                );
+
+                (uuid, crate::timestamp::decode_unix_timestamp_millis(&uuid))
+            })
+            .collect()
+    }
+
+    /// Create a new version 7 UUID using a time value measured from a
+    /// monotonic clock and random bytes.
+    ///
+    /// The timestamp is `base + start.elapsed()`, where `base` is the
+    /// system time that was read when `start` was captured. Since the system
+    /// clock is never read again, adjustments to it (like NTP corrections or
+    /// a user changing the time) can't make the timestamps of UUIDs generated
+    /// from the same `base` and `start` go backwards. Sharing one `base` and
+    /// `start` for the life of a process gives UUIDs that sort in the order
+    /// they were generated.
+    ///
+    /// The tradeoff is accuracy. A monotonic clock only measures elapsed
+    /// time, so if the system time was wrong when `base` was read, or drifts
+    /// from the monotonic clock while the process runs, the timestamps will
+    /// drift too and won't agree with UUIDs generated by other processes using
+    /// [`Uuid::now_v7`]. Read `base` and `start` again when an accurate
+    /// wall-clock time matters more than in-process ordering.
+    ///
+    /// Note that usage of this method requires the `v7` and `std` features
+    /// of this crate to be enabled.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `base + start.elapsed()` is before the Unix
+    /// epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use uuid::Uuid;
+    /// use std::time::{Instant, SystemTime};
+    ///
+    /// let base = SystemTime::now();
+    /// let start = Instant::now();
+    ///
+    /// let first = Uuid::new_v7_instant(base, start);
+    /// let second = Uuid::new_v7_instant(base, start);
+    ///
+    /// assert!(first.unix_millis().unwrap() <= second.unix_millis().unwrap());
+    /// ```
+    ///
+    /// [`Uuid::now_v7`]: #method.now_v7
+    #[cfg(feature = "std")]
+    pub fn new_v7_instant(base: std::time::SystemTime, start: std::time::Instant) -> Self {
+        let millis = (base + start.elapsed())
+            .duration_since(std::time::SystemTime::UNIX_EPOCH)
+            .expect("the time is before the Unix epoch")
+            .as_millis() as u64;
+
+        Builder::from_unix_timestamp_millis(millis, &rng::bytes()[..10].try_into().unwrap())
+            .into_uuid()
+    }
+}
+
+/// Create a version 7 UUID from the 122 bits that aren't its version or variant.
+fn pack_payload(payload: u128) -> Uuid {
+    let unix_ts_ms = payload >> 74;
+    let rand_a = (payload >> 62) & 0xfff;
+    let rand_b = payload & ((1 << 62) - 1);
+
+    Builder::from_u128(unix_ts_ms << 80 | rand_a << 64 | rand_b)
+        .with_variant(Variant::RFC4122)
+        .with_version(Version::SortRand)
+        .into_uuid()
+}
+
+/// Get the 122 bits of a version 7 UUID that aren't its version or variant.
+fn unpack_payload(uuid: Uuid) -> u128 {
+    let v = uuid.as_u128();
+
+    let unix_ts_ms = v >> 80;
+    let rand_a = (v >> 64) & 0xfff;
+    let rand_b = v & ((1 << 62) - 1);
+
+    unix_ts_ms << 74 | rand_a << 62 | rand_b
+}
+
+/// Create a version 7 UUID from the next value of `counter` that's at least `floor`.
+///
+/// The counter holds a Unix timestamp in milliseconds shifted left by 12 bits,
+/// with the value of the `rand_a` field in the low 12 bits.
+#[cfg(target_has_atomic = "64")]
+fn next_with_counter(floor: u64, counter: &AtomicU64, mut random_bytes: [u8; 10]) -> Uuid {
+    // The counter is the only shared state, so relaxed ordering is enough
+    // for every caller to see a distinct, increasing value
+    let prev = counter
+        .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |prev| {
+            Some(prev.saturating_add(1).max(floor))
+        })
+        .unwrap();
+    let next = prev.saturating_add(1).max(floor);
+
+    let rand_a = (next & 0xfff) as u16;
+
+    random_bytes[0] = rand_a as u8;
+    random_bytes[1] = (rand_a >> 8) as u8;
+
+    Builder::from_unix_timestamp_millis(next >> 12, &random_bytes).into_uuid()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "rand_core")]
+    use crate::external::rand_core_support::tests::StepRng;
     use crate::{std::string::ToString, NoContext, Variant, Version};
     #[cfg(target_arch = "wasm32")]
     use wasm_bindgen_test::*;
@@ -104,9 +574,25 @@ mod tests {
         let uuid2 = Uuid::new_v7(Timestamp::from_unix(NoContext, time2, time_fraction2));
 
         assert!(uuid1.as_bytes() < uuid2.as_bytes());
+        assert!(uuid1.as_u128() < uuid2.as_u128());
         assert!(uuid1.to_string() < uuid2.to_string());
     }
 
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_millis_roundtrip() {
+        let millis: u64 = 1_496_854_535_812;
+
+        let uuid = Uuid::new_v7(Timestamp::from_unix(
+            NoContext,
+            millis / 1000,
+            ((millis % 1000) * 1_000_000) as u32,
+        ));
+
+        // The first 48 bits are the Unix timestamp in milliseconds
+        assert_eq!((uuid.as_u128() >> 80) as u64, millis);
+    }
+
     #[test]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
     fn test_new_timestamp_roundtrip() {
@@ -121,4 +607,405 @@ mod tests {
 
         assert_eq!(ts.to_unix(), decoded_ts.to_unix());
     }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_replay() {
+        let mut rng = crate::tests::xorshift(1);
+        let recorded = [rng(), rng()].concat();
+
+        let replay = || {
+            let mut pool = &recorded[..];
+
+            let uuids = (0..3)
+                .map(|i| {
+                    let ts = Timestamp::from_unix(NoContext, 1_496_854_535 + i, 812_000_000);
+
+                    Uuid::new_v7_replay(ts, &mut pool).unwrap()
+                })
+                .collect::<std::vec::Vec<_>>();
+
+            (uuids, pool.len())
+        };
+
+        let (uuids, remaining) = replay();
+
+        assert_eq!(replay(), (uuids.clone(), remaining));
+        assert_eq!(remaining, 2);
+
+        for (uuid, random_bytes) in uuids.iter().zip(recorded.chunks(10)) {
+            assert_eq!(uuid.get_version(), Some(Version::SortRand));
+            assert_eq!(uuid.get_variant(), Variant::RFC4122);
+
+            let ts = uuid.get_timestamp().unwrap();
+            let expected = Uuid::new_v7_replay(ts, &mut &random_bytes[..]).unwrap();
+
+            assert_eq!(*uuid, expected);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_replay_exhausted() {
+        let ts = Timestamp::from_unix(NoContext, 1_496_854_535, 812_000_000);
+
+        let recorded = [0; 9];
+        let mut pool = &recorded[..];
+
+        assert_eq!(
+            Uuid::new_v7_replay(ts, &mut pool),
+            Err(Error(ErrorKind::PoolExhausted { remaining: 9 }))
+        );
+        assert_eq!(pool.len(), 9);
+
+        let mut pool: &[u8] = &[];
+        assert_eq!(
+            Uuid::new_v7_replay(ts, &mut pool),
+            Err(Error(ErrorKind::PoolExhausted { remaining: 0 }))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(all(feature = "rand_core", target_has_atomic = "64"))]
+    fn test_new_with_counter() {
+        let counter = AtomicU64::new(0);
+        let mut rng = StepRng(0);
+
+        let ts = Timestamp::from_unix(NoContext, 1_645_557_742, 0);
+
+        let uuid1 = Uuid::new_v7_with_counter(ts, &counter, &mut rng);
+        let uuid2 = Uuid::new_v7_with_counter(ts, &counter, &mut rng);
+
+        assert_eq!(uuid1.get_version(), Some(Version::SortRand));
+        assert_eq!(uuid1.get_variant(), Variant::RFC4122);
+
+        assert_eq!(&uuid1.to_string()[..19], "017f22e2-79b0-7000-");
+        assert_eq!(&uuid2.to_string()[..19], "017f22e2-79b0-7001-");
+
+        assert_eq!(counter.load(Ordering::Relaxed), 1_645_557_742_000 << 12 | 1);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(all(feature = "rand_core", target_has_atomic = "64"))]
+    fn test_new_with_counter_time_goes_backwards() {
+        let counter = AtomicU64::new(0);
+        let mut rng = StepRng(0);
+
+        let uuid1 = Uuid::new_v7_with_counter(
+            Timestamp::from_unix(NoContext, 1_645_557_742, 0),
+            &counter,
+            &mut rng,
+        );
+        let uuid2 = Uuid::new_v7_with_counter(
+            Timestamp::from_unix(NoContext, 1_645_557_000, 0),
+            &counter,
+            &mut rng,
+        );
+
+        assert!(uuid1 < uuid2);
+        assert_eq!(uuid1.get_timestamp(), uuid2.get_timestamp());
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(all(feature = "rand_core", target_has_atomic = "64"))]
+    fn test_new_with_counter_overflow() {
+        let counter = AtomicU64::new(0);
+        let mut rng = StepRng(0);
+
+        let ts = Timestamp::from_unix(NoContext, 1_645_557_742, 0);
+
+        let mut prev = Uuid::new_v7_with_counter(ts, &counter, &mut rng);
+        for _ in 0..5000 {
+            let uuid = Uuid::new_v7_with_counter(ts, &counter, &mut rng);
+            assert!(prev < uuid);
+            prev = uuid;
+        }
+
+        // The sequence overflowed into the next millisecond
+        assert_eq!(
+            prev.get_timestamp().unwrap().to_unix(),
+            (1_645_557_742, 1_000_000)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(all(feature = "rand_core", target_has_atomic = "64"))]
+    fn test_new_with_counter_resume() {
+        let counter = AtomicU64::new(0);
+        let mut rng = StepRng(0);
+
+        let ts = Timestamp::from_unix(NoContext, 1_645_557_742, 0);
+
+        let uuid1 = Uuid::new_v7_with_counter(ts, &counter, &mut rng);
+
+        let resumed = AtomicU64::new(counter.load(Ordering::Relaxed));
+        let uuid2 = Uuid::new_v7_with_counter(ts, &resumed, &mut rng);
+
+        assert!(uuid1 < uuid2);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_counter_bits() {
+        let ts = Timestamp::from_unix(NoContext, 1_496_854_535, 812_000_000);
+        let mut last = Uuid::nil();
+
+        let mut rng = crate::tests::xorshift(1);
+
+        let mut prev = Uuid::nil();
+        for i in 0..10_000 {
+            let random_bytes = rng()[..10].try_into().unwrap();
+            let uuid = Uuid::new_v7_counter_bits(ts, &mut last, 42, &random_bytes).unwrap();
+
+            assert_eq!(uuid.get_version(), Some(Version::SortRand));
+            assert_eq!(uuid.get_variant(), Variant::RFC4122);
+            assert_eq!(
+                uuid.get_timestamp().unwrap().to_unix(),
+                (1_496_854_535, 812_000_000)
+            );
+            assert_eq!(unpack_payload(uuid) >> 32 & ((1 << 42) - 1), i);
+            assert_eq!(uuid, last);
+            assert!(uuid > prev);
+
+            prev = uuid;
+        }
+
+        // The counter starts again from zero when the time moves forwards
+        let ts = Timestamp::from_unix(NoContext, 1_496_854_536, 812_000_000);
+        let uuid = Uuid::new_v7_counter_bits(ts, &mut last, 42, &[0; 10]).unwrap();
+
+        assert!(uuid > prev);
+        assert_eq!(unpack_payload(uuid) & ((1 << 74) - 1), 0);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_counter_bits_widths() {
+        let ts = Timestamp::from_unix(NoContext, 1_496_854_535, 812_000_000);
+
+        for bits in 0..=COUNTER_BITS_MAX {
+            let mut last = Uuid::nil();
+
+            let uuid1 = Uuid::new_v7_counter_bits(ts, &mut last, bits, &[0xff; 10]).unwrap();
+            let uuid2 = Uuid::new_v7_counter_bits(ts, &mut last, bits, &[0x00; 10]).unwrap();
+
+            assert!(uuid1 < uuid2, "{} bits", bits);
+        }
+
+        // Changing the width of the counter keeps UUIDs ordered
+        let mut last = Uuid::nil();
+        let uuid1 = Uuid::new_v7_counter_bits(ts, &mut last, 12, &[0xff; 10]).unwrap();
+        let uuid2 = Uuid::new_v7_counter_bits(ts, &mut last, 42, &[0x00; 10]).unwrap();
+
+        assert!(uuid1 < uuid2);
+
+        assert_eq!(
+            Uuid::new_v7_counter_bits(ts, &mut last, 75, &[0; 10]),
+            Err(Error(ErrorKind::CounterBits { bits: 75 }))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_payload_roundtrip() {
+        let mut rng = crate::tests::xorshift(1);
+
+        for _ in 0..100 {
+            let uuid = Builder::from_bytes(rng())
+                .with_variant(Variant::RFC4122)
+                .with_version(Version::SortRand)
+                .into_uuid();
+
+            assert_eq!(pack_payload(unpack_payload(uuid)), uuid);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_with_clock() {
+        use crate::std::cell::Cell;
+
+        struct SteppingClock(Cell<u64>);
+
+        impl Clock for SteppingClock {
+            fn now_millis(&self) -> u64 {
+                let now = self.0.get();
+                self.0.set(now + 5);
+
+                now
+            }
+        }
+
+        let clock = SteppingClock(Cell::new(1_645_557_742_000));
+
+        let uuids = [
+            Uuid::new_v7_with_clock(&clock),
+            Uuid::new_v7_with_clock(&clock),
+            Uuid::new_v7_with_clock(&clock),
+        ];
+
+        for (i, uuid) in uuids.iter().enumerate() {
+            assert_eq!(uuid.get_version(), Some(Version::SortRand));
+            assert_eq!(uuid.unix_millis(), Some(1_645_557_742_000 + 5 * i as u64));
+        }
+
+        assert!(uuids[0] < uuids[1]);
+        assert!(uuids[1] < uuids[2]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_now_with_system_clock() {
+        use crate::timestamp::SystemClock;
+
+        let before = SystemClock.now_millis();
+        let uuid = Uuid::now_v7();
+        let after = SystemClock.now_millis();
+
+        let millis = uuid.unix_millis().unwrap();
+
+        assert!(before <= millis && millis <= after);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(target_has_atomic = "64")]
+    fn test_new_high_res() {
+        let counter = AtomicU64::new(0);
+
+        let uuid1 = Uuid::new_v7_high_res(
+            Timestamp::from_unix(NoContext, 1_645_557_742, 1_000),
+            &counter,
+        );
+        let uuid2 = Uuid::new_v7_high_res(
+            Timestamp::from_unix(NoContext, 1_645_557_742, 999_000),
+            &counter,
+        );
+
+        assert_eq!(uuid1.get_version(), Some(Version::SortRand));
+        assert_eq!(uuid1.get_variant(), Variant::RFC4122);
+
+        // Both are in the same millisecond, but ordered by their nanoseconds
+        assert_eq!(uuid1.get_timestamp(), uuid2.get_timestamp());
+        assert!(uuid1 < uuid2);
+
+        assert_eq!(&uuid1.to_string()[..19], "017f22e2-79b0-7004-");
+        assert_eq!(&uuid2.to_string()[..19], "017f22e2-79b0-7ffb-");
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(target_has_atomic = "64")]
+    fn test_new_high_res_collision() {
+        let counter = AtomicU64::new(0);
+
+        let ts = Timestamp::from_unix(NoContext, 1_645_557_742, 999_999);
+
+        let uuid1 = Uuid::new_v7_high_res(ts, &counter);
+        let uuid2 = Uuid::new_v7_high_res(ts, &counter);
+
+        assert!(uuid1 < uuid2);
+        assert_eq!(&uuid1.to_string()[..19], "017f22e2-79b0-7fff-");
+
+        // The fraction overflowed into the next millisecond
+        assert_eq!(&uuid2.to_string()[..19], "017f22e2-79b1-7000-");
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(all(feature = "std", target_has_atomic = "64"))]
+    fn test_now_batch() {
+        let batch = Uuid::now_v7_batch(5000);
+
+        assert_eq!(batch.len(), 5000);
+
+        for (uuid, millis) in batch.iter() {
+            assert_eq!(uuid.get_version(), Some(Version::SortRand));
+            assert_eq!(uuid.unix_millis(), Some(*millis));
+        }
+
+        for pair in batch.windows(2) {
+            assert!(pair[0].0 < pair[1].0);
+            assert!(pair[0].1 <= pair[1].1);
+        }
+
+        // The counter carried into the timestamp after 4096 UUIDs
+        assert_eq!(batch[0].1, batch[4095].1);
+        assert_eq!(batch[0].1 + 1, batch[4096].1);
+
+        assert!(Uuid::now_v7_batch(0).is_empty());
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+    fn test_new_instant() {
+        use crate::std::time::{Duration, Instant, SystemTime};
+
+        // A base well behind the real system time, as if the clock had been set back
+        let base_millis = 1_645_557_742_000;
+        let base = SystemTime::UNIX_EPOCH + Duration::from_millis(base_millis);
+        let start = Instant::now();
+
+        let mut last = base_millis;
+        for _ in 0..100 {
+            let uuid = Uuid::new_v7_instant(base, start);
+            let millis = uuid.unix_millis().unwrap();
+
+            assert_eq!(Some(Version::SortRand), uuid.get_version());
+            assert!(millis >= last);
+
+            last = millis;
+        }
+
+        assert!(last - base_millis < 60_000);
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "std",
+        feature = "rand_core",
+        target_has_atomic = "64",
+        not(target_arch = "wasm32")
+    ))]
+    fn test_new_with_counter_concurrent() {
+        use crate::std::{sync::Arc, thread, vec::Vec};
+
+        let counter = Arc::new(AtomicU64::new(0));
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let counter = counter.clone();
+
+                thread::spawn(move || {
+                    let mut rng = StepRng(0);
+                    let ts = Timestamp::from_unix(NoContext, 1_645_557_742, 0);
+
+                    (0..2000)
+                        .map(|_| Uuid::new_v7_with_counter(ts, &counter, &mut rng))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let mut all = Vec::new();
+        for handle in handles {
+            let uuids = handle.join().unwrap();
+
+            for pair in uuids.windows(2) {
+                assert!(pair[0] < pair[1]);
+            }
+
+            all.extend(uuids);
+        }
+
+        // No two threads were handed the same timestamp and sequence number
+        let mut prefixes: Vec<_> = all.iter().map(|uuid| uuid.as_u128() >> 64).collect();
+        prefixes.sort_unstable();
+        prefixes.dedup();
+        assert_eq!(prefixes.len(), 8 * 2000);
+    }
 }
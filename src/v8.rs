@@ -1,4 +1,7 @@
-use crate::{Builder, Uuid};
+use crate::{
+    error::{Error, ErrorKind},
+    Builder, Timestamp, Uuid, Version,
+};
 
 impl Uuid {
     /// Creates a custom UUID comprised almost entirely of user-supplied bytes.
@@ -28,12 +31,308 @@ impl Uuid {
     pub fn new_v8(buf: [u8; 16]) -> Uuid {
         Builder::from_custom_bytes(buf).into_uuid()
     }
+
+    /// Creates a custom UUID that starts with an application tag, followed
+    /// by a timestamp and random bytes.
+    ///
+    /// The layout is:
+    ///
+    /// * bytes `0..2`: the big-endian `tag`.
+    /// * bytes `2..6`: the big-endian Unix timestamp in whole seconds, truncated to 32 bits.
+    /// * bytes `6..16`: the `random_bytes`, with the version and variant injected.
+    ///
+    /// UUIDs with the same tag sort by their timestamp, so all the IDs
+    /// of an application share a recognizable prefix while staying roughly
+    /// time-ordered. UUIDs generated within the same second are ordered randomly.
+    ///
+    /// Only 32 bits of the timestamp are kept, so timestamps past
+    /// `2106-02-07T06:28:15Z` wrap around to the start of the Unix epoch and
+    /// will no longer sort after earlier ones.
+    ///
+    /// This is a version 8 UUID rather than a version 7 one because a version
+    /// 7 UUID must start with a 48 bit Unix timestamp in milliseconds. Putting
+    /// a tag and a truncated timestamp there would produce UUIDs that claim
+    /// to be version 7 but decode to the wrong time and don't sort across
+    /// applications, while version 8 leaves the layout up to the application.
+    ///
+    /// Note that usage of this method requires the `v8` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Timestamp, NoContext, Version};
+    /// let ts = Timestamp::from_unix(NoContext, 1497624119, 1234);
+    ///
+    /// let uuid = Uuid::new_tagged_v8(0xa11c, ts, &[0; 10]);
+    ///
+    /// assert_eq!(Some(Version::Custom), uuid.get_version());
+    /// assert_eq!(Some(0xa11c), uuid.get_tag());
+    /// assert_eq!(
+    ///     "a11c5943-ee37-8000-8000-000000000000",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// ```
+    pub fn new_tagged_v8(tag: u16, ts: Timestamp, random_bytes: &[u8; 10]) -> Uuid {
+        let (secs, _) = ts.to_unix();
+
+        let tag = tag.to_be_bytes();
+        let secs = (secs as u32).to_be_bytes();
+
+        Builder::from_custom_bytes([
+            tag[0],
+            tag[1],
+            secs[0],
+            secs[1],
+            secs[2],
+            secs[3],
+            random_bytes[0],
+            random_bytes[1],
+            random_bytes[2],
+            random_bytes[3],
+            random_bytes[4],
+            random_bytes[5],
+            random_bytes[6],
+            random_bytes[7],
+            random_bytes[8],
+            random_bytes[9],
+        ])
+        .into_uuid()
+    }
+
+    /// Returns the application tag of a UUID created with [`Uuid::new_tagged_v8`].
+    ///
+    /// The tag is the first 2 bytes of the UUID. This method will return `None`
+    /// if the UUID isn't a version 8 UUID.
+    ///
+    /// [`Uuid::new_tagged_v8`]: #method.new_tagged_v8
+    pub const fn get_tag(&self) -> Option<u16> {
+        match self.get_version() {
+            Some(Version::Custom) => {
+                let bytes = self.as_bytes();
+
+                Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+            }
+            _ => None,
+        }
+    }
+
+    /// Creates a custom UUID by packing a sequence of bit fields.
+    ///
+    /// Each segment is a `(value, bits)` pair. Segments are concatenated
+    /// most-significant first into the 128 bits of the UUID, and any bits
+    /// left over at the end are zero. The version and variant are stamped on
+    /// afterwards, which overwrites the 4 bits starting at the 48th bit and
+    /// the 2 bits starting at the 64th bit, whichever segments they fall in.
+    ///
+    /// That means a layout that uses all 128 bits, like
+    /// `(u32, u16, u16, u64)`, works, but the top 4 bits of the third field
+    /// will always read back as `8` and the top 2 bits of the fourth as `10`.
+    /// Leave those bits out of the values you care about, or set them to
+    /// those values up front, to get every segment back unchanged from
+    /// [`Uuid::v8_segments`].
+    ///
+    /// This method will return an error if the segments need more than 128
+    /// bits in total, or if a value doesn't fit into its width.
+    ///
+    /// Note that usage of this method requires the `v8` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::{Uuid, Version};
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::from_v8_segments(&[
+    ///     (0x6380_7a5c, 32),
+    ///     (0x0017, 16),
+    ///     (0x0002, 16),
+    ///     (0x1d4, 64),
+    /// ])?;
+    ///
+    /// assert_eq!(Some(Version::Custom), uuid.get_version());
+    /// assert_eq!(
+    ///     "63807a5c-0017-8002-8000-0000000001d4",
+    ///     uuid.hyphenated().to_string(),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Uuid::v8_segments`]: #method.v8_segments
+    pub const fn from_v8_segments(segments: &[(u128, u32)]) -> Result<Uuid, Error> {
+        let mut packed = 0u128;
+        let mut total = 0u32;
+
+        let mut i = 0;
+        while i < segments.len() {
+            let (value, bits) = segments[i];
+
+            total = total.saturating_add(bits);
+            if total > 128 {
+                return Err(Error(ErrorKind::SegmentBits { bits: total }));
+            }
+
+            if bits < 128 && value >> bits != 0 {
+                return Err(Error(ErrorKind::SegmentValue { index: i, bits }));
+            }
+
+            if bits == 128 {
+                packed = value;
+            } else if bits > 0 {
+                packed = packed << bits | value;
+            }
+
+            i += 1;
+        }
+
+        let packed = if total > 0 {
+            packed << (128 - total)
+        } else {
+            0
+        };
+
+        Ok(Builder::from_custom_bytes(packed.to_be_bytes()).into_uuid())
+    }
+
+    /// Splits a custom UUID back into bit fields of the given widths.
+    ///
+    /// This is the inverse of [`Uuid::from_v8_segments`]. Fields are read
+    /// most-significant first from the 128 bits of the UUID, so decoding with
+    /// the same widths that were used to compose a UUID returns the original
+    /// values. The version and variant bits are part of whichever fields
+    /// they fall in, so those fields contain the stamped bits rather than
+    /// whatever was originally passed in their place.
+    ///
+    /// For a fixed number of fields, or without the `std` feature, use
+    /// [`Uuid::v8_segments_array`] instead.
+    ///
+    /// This method will return an error if the widths add up to more than
+    /// 128 bits.
+    ///
+    /// Note that usage of this method requires the `v8` and `std` features of
+    /// this crate to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("63807a5c-0017-8002-8000-0000000001d4")?;
+    ///
+    /// let segments = uuid.v8_segments(&[32, 16, 16, 64])?;
+    ///
+    /// // The last two segments include the version and variant bits
+    /// assert_eq!(vec![0x6380_7a5c, 0x0017, 0x8002, 0x8000_0000_0000_01d4], segments);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Uuid::from_v8_segments`]: #method.from_v8_segments
+    /// [`Uuid::v8_segments_array`]: #method.v8_segments_array
+    #[cfg(feature = "std")]
+    pub fn v8_segments(&self, widths: &[u32]) -> Result<std::vec::Vec<u128>, Error> {
+        let mut total = 0u32;
+        for &bits in widths {
+            total = total.saturating_add(bits);
+            if total > 128 {
+                return Err(Error(ErrorKind::SegmentBits { bits: total }));
+            }
+        }
+
+        let mut packed = self.as_u128();
+
+        Ok(widths
+            .iter()
+            .map(|&bits| {
+                if bits == 0 {
+                    return 0;
+                }
+
+                let segment = packed >> (128 - bits);
+                packed = if bits < 128 { packed << bits } else { 0 };
+
+                segment
+            })
+            .collect())
+    }
+
+    /// Splits a custom UUID back into a fixed number of bit fields.
+    ///
+    /// This is the same as [`Uuid::v8_segments`], but returns an array, so
+    /// it doesn't allocate and can be used in `const` contexts.
+    ///
+    /// This method will return an error if the widths add up to more than
+    /// 128 bits.
+    ///
+    /// Note that usage of this method requires the `v8` feature of this crate
+    /// to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use uuid::Uuid;
+    /// # fn main() -> Result<(), uuid::Error> {
+    /// let uuid = Uuid::parse_str("63807a5c-0017-8002-8000-0000000001d4")?;
+    ///
+    /// let [epoch, region, kind, seq] = uuid.v8_segments_array([32, 16, 16, 64])?;
+    ///
+    /// assert_eq!(
+    ///     (0x6380_7a5c, 0x0017, 0x8002, 0x8000_0000_0000_01d4),
+    ///     (epoch, region, kind, seq),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Uuid::v8_segments`]: #method.v8_segments
+    pub const fn v8_segments_array<const N: usize>(
+        &self,
+        widths: [u32; N],
+    ) -> Result<[u128; N], Error> {
+        let mut total = 0u32;
+
+        let mut i = 0;
+        while i < N {
+            total = total.saturating_add(widths[i]);
+            if total > 128 {
+                return Err(Error(ErrorKind::SegmentBits { bits: total }));
+            }
+
+            i += 1;
+        }
+
+        let mut packed = self.as_u128();
+        let mut segments = [0; N];
+
+        let mut i = 0;
+        while i < N {
+            let bits = widths[i];
+
+            if bits > 0 {
+                segments[i] = packed >> (128 - bits);
+                packed = if bits < 128 { packed << bits } else { 0 };
+            }
+
+            i += 1;
+        }
+
+        Ok(segments)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Variant, Version};
+    use crate::{NoContext, Variant, Version};
     use std::string::ToString;
 
     #[cfg(target_arch = "wasm32")]
@@ -54,4 +353,282 @@ mod tests {
             "0f0e0d0c-0b0a-8908-8706-050403020100"
         );
     }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_any_bytes() {
+        let mut rng = crate::tests::xorshift(1);
+
+        for buf in [[0x00; 16], [0xff; 16], *Uuid::NAMESPACE_DNS.as_bytes()]
+            .iter()
+            .copied()
+            .chain((0..100).map(|_| rng()))
+        {
+            let uuid = Uuid::new_v8(buf);
+
+            assert_eq!(uuid.get_version_num(), 8);
+            assert_eq!(uuid.get_variant(), Variant::RFC4122);
+
+            // Only the version and variant bits are changed
+            let mask = !(0xf << 76 | 0b11 << 62);
+            assert_eq!(uuid.as_u128() & mask, u128::from_be_bytes(buf) & mask);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_from_v8_segments() {
+        let uuid =
+            Uuid::from_v8_segments(&[(0x0123_4567_89ab, 48), (0xcde, 12), (0x3, 2)]).unwrap();
+
+        assert_eq!(uuid.get_version(), Some(Version::Custom));
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+        assert_eq!(
+            uuid.hyphenated().to_string(),
+            "01234567-89ab-8dec-8000-000000000000"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_from_v8_segments_exact_fit() {
+        let uuid =
+            Uuid::from_v8_segments(&[(u64::MAX as u128, 64), (u64::MAX as u128, 64)]).unwrap();
+
+        assert_eq!(
+            uuid.hyphenated().to_string(),
+            "ffffffff-ffff-8fff-bfff-ffffffffffff"
+        );
+
+        let uuid = Uuid::from_v8_segments(&[(u128::MAX, 128)]).unwrap();
+
+        assert_eq!(
+            uuid.hyphenated().to_string(),
+            "ffffffff-ffff-8fff-bfff-ffffffffffff"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_from_v8_segments_full_layout() {
+        let uuid = Uuid::from_v8_segments(&[
+            (0x6380_7a5c, 32),
+            (0x0017, 16),
+            (0xf002, 16),
+            (0xffff_0000_0000_01d4, 64),
+        ])
+        .unwrap();
+
+        assert_eq!(uuid.get_version(), Some(Version::Custom));
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+
+        // The version and variant overwrite the top bits of the last two segments
+        assert_eq!(
+            uuid.hyphenated().to_string(),
+            "63807a5c-0017-8002-bfff-0000000001d4"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_from_v8_segments_empty() {
+        let uuid = Uuid::from_v8_segments(&[]).unwrap();
+
+        assert_eq!(uuid.get_version(), Some(Version::Custom));
+        assert_eq!(
+            uuid.hyphenated().to_string(),
+            "00000000-0000-8000-8000-000000000000"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_from_v8_segments_too_many_bits() {
+        assert_eq!(
+            Uuid::from_v8_segments(&[(0, 64), (0, 65)]),
+            Err(Error(ErrorKind::SegmentBits { bits: 129 }))
+        );
+        assert_eq!(
+            Uuid::from_v8_segments(&[(0, u32::MAX), (0, 1)]),
+            Err(Error(ErrorKind::SegmentBits { bits: u32::MAX }))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_from_v8_segments_value_too_wide() {
+        assert_eq!(
+            Uuid::from_v8_segments(&[(0xff, 8), (0x100, 8)]),
+            Err(Error(ErrorKind::SegmentValue { index: 1, bits: 8 }))
+        );
+        assert_eq!(
+            Uuid::from_v8_segments(&[(1, 0)]),
+            Err(Error(ErrorKind::SegmentValue { index: 0, bits: 0 }))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_v8_segments_roundtrip() {
+        // The version and variant bits are already set in the last two segments
+        let segments = [
+            (0x6380_7a5c, 32),
+            (0x0017, 16),
+            (0x8ffe, 16),
+            (0xbfff_ffff_ffff_fffd, 64),
+        ];
+
+        let uuid = Uuid::from_v8_segments(&segments).unwrap();
+
+        assert_eq!(
+            uuid.v8_segments_array([32, 16, 16, 64]).unwrap(),
+            segments.map(|(value, _)| value)
+        );
+
+        // Otherwise they're replaced
+        let uuid = Uuid::from_v8_segments(&[(0, 32), (0, 16), (0xfffe, 16), (0, 64)]).unwrap();
+
+        assert_eq!(
+            uuid.v8_segments_array([32, 16, 16, 64]).unwrap(),
+            [0, 0, 0x8ffe, 0x8000_0000_0000_0000]
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_v8_segments_partial() {
+        let uuid = Uuid::from_v8_segments(&[(0xab, 8), (0x1, 1), (0x0, 0), (0x7f, 7)]).unwrap();
+
+        assert_eq!(
+            uuid.v8_segments_array([8, 1, 0, 7]).unwrap(),
+            [0xab, 0x1, 0x0, 0x7f]
+        );
+        assert_eq!(uuid.v8_segments_array([]).unwrap(), []);
+        assert_eq!(uuid.v8_segments_array([4, 4]).unwrap(), [0xa, 0xb]);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_v8_segments_includes_version_and_variant() {
+        let uuid = Uuid::parse_str("ffffffff-ffff-8fff-bfff-ffffffffffff").unwrap();
+
+        assert_eq!(uuid.v8_segments_array([128]).unwrap(), [uuid.as_u128()]);
+        assert_eq!(
+            uuid.v8_segments_array([48, 4, 12, 2, 62]).unwrap(),
+            [(1 << 48) - 1, 0x8, (1 << 12) - 1, 0b10, (1 << 62) - 1]
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_v8_segments_too_many_bits() {
+        let uuid = Uuid::new_v8([0; 16]);
+
+        assert_eq!(
+            uuid.v8_segments_array([64, 65]),
+            Err(Error(ErrorKind::SegmentBits { bits: 129 }))
+        );
+        assert_eq!(
+            uuid.v8_segments_array([1, 128]),
+            Err(Error(ErrorKind::SegmentBits { bits: 129 }))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(feature = "std")]
+    fn test_v8_segments_vec_roundtrip() {
+        let (epoch, region, kind, seq) = (0x6380_7a5c, 0x0017, 0x8ffe, 0xbfff_ffff_ffff_fffd);
+
+        let uuid =
+            Uuid::from_v8_segments(&[(epoch, 32), (region, 16), (kind, 16), (seq, 64)]).unwrap();
+
+        assert_eq!(
+            uuid.v8_segments(&[32, 16, 16, 64]).unwrap(),
+            [epoch, region, kind, seq]
+        );
+        assert_eq!(
+            uuid.v8_segments(&[32, 16, 16, 64]).unwrap(),
+            uuid.v8_segments_array([32, 16, 16, 64]).unwrap()
+        );
+
+        assert_eq!(uuid.v8_segments(&[]).unwrap(), []);
+        assert_eq!(uuid.v8_segments(&[0, 128]).unwrap(), [0, uuid.as_u128()]);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg(feature = "std")]
+    fn test_v8_segments_vec_too_many_bits() {
+        let uuid = Uuid::new_v8([0; 16]);
+
+        assert_eq!(
+            uuid.v8_segments(&[64, 65]),
+            Err(Error(ErrorKind::SegmentBits { bits: 129 }))
+        );
+        assert_eq!(
+            uuid.v8_segments(&[u32::MAX, 1]),
+            Err(Error(ErrorKind::SegmentBits { bits: u32::MAX }))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_tagged_v8() {
+        let ts = Timestamp::from_unix(NoContext, 0x0123_4567, 0);
+        let uuid = Uuid::new_tagged_v8(0xbeef, ts, &[0xff; 10]);
+
+        assert_eq!(uuid.get_version(), Some(Version::Custom));
+        assert_eq!(uuid.get_variant(), Variant::RFC4122);
+        assert_eq!(uuid.get_tag(), Some(0xbeef));
+        assert_eq!(
+            uuid.hyphenated().to_string(),
+            "beef0123-4567-8fff-bfff-ffffffffffff"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_tagged_v8_sorts_by_time_within_tag() {
+        let mut prev =
+            Uuid::new_tagged_v8(0x0001, Timestamp::from_unix(NoContext, 0, 0), &[0xff; 10]);
+
+        for secs in (1..u32::MAX as u64).step_by(7_777_777) {
+            let ts = Timestamp::from_unix(NoContext, secs, 999_999_999);
+            let uuid = Uuid::new_tagged_v8(0x0001, ts, &[(secs % 256) as u8; 10]);
+
+            assert_eq!(uuid.get_tag(), Some(0x0001));
+            assert!(prev < uuid, "{} < {}", prev, uuid);
+
+            prev = uuid;
+        }
+
+        // Every ID with a higher tag sorts after every ID with a lower one
+        let other = Uuid::new_tagged_v8(0x0002, Timestamp::from_unix(NoContext, 0, 0), &[0; 10]);
+        assert!(prev < other);
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_new_tagged_v8_truncates_timestamp() {
+        let ts = Timestamp::from_unix(NoContext, u32::MAX as u64 + 2, 0);
+        let uuid = Uuid::new_tagged_v8(0, ts, &[0; 10]);
+
+        assert_eq!(
+            uuid.hyphenated().to_string(),
+            "00000000-0001-8000-8000-000000000000"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    fn test_get_tag_not_v8() {
+        assert_eq!(Uuid::nil().get_tag(), None);
+        assert_eq!(
+            Uuid::parse_str("beef0123-4567-4fff-bfff-ffffffffffff")
+                .unwrap()
+                .get_tag(),
+            None
+        );
+    }
 }